@@ -0,0 +1,90 @@
+use crate::render::{Format, Picture, Rgb};
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+// Saves each frame as its own numbered file, frame0000.ppm and so on, ready
+// for ffmpeg or an image viewer to step through
+pub fn write_frames(frames: &[Picture], dir: &Path, format: Format) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let extension = match format {
+        Format::Ppm => "ppm",
+        Format::Pgm => "pgm",
+        Format::Svg => "svg",
+    };
+    for (n, frame) in frames.iter().enumerate() {
+        fs::write(
+            dir.join(format!("frame{:04}.{}", n, extension)),
+            format.encode(frame),
+        )?;
+    }
+    Ok(())
+}
+
+// Draws a frame in a truecolor terminal.  Each character is two cells, one
+// above the other, as the foreground and background of a half block.
+pub fn ansi(picture: &Picture) -> String {
+    let mut out = String::new();
+    for y in (0..picture.height()).step_by(2) {
+        for x in 0..picture.width() {
+            let Rgb(r, g, b) = picture[(x, y)];
+            let Rgb(br, bg, bb) = if y + 1 < picture.height() {
+                picture[(x, y + 1)]
+            } else {
+                Rgb::BLACK
+            };
+            write!(
+                out,
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                r, g, b, br, bg, bb
+            )
+            .unwrap();
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+// Plays the frames over each other from the top of the screen
+pub fn play(frames: &[Picture], out: &mut impl Write, fps: u32) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / fps.max(1);
+    write!(out, "\x1b[2J")?;
+    for (n, frame) in frames.iter().enumerate() {
+        writeln!(out, "\x1b[H{}frame {}/{}", ansi(frame), n + 1, frames.len())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ansi() {
+    use crate::grid::Grid;
+
+    let picture = Grid::from_cells(1, 3, vec![Rgb::RED, Rgb::WHITE, Rgb(1, 2, 3)]);
+    assert_eq!(
+        ansi(&picture),
+        "\x1b[38;2;220;30;30m\x1b[48;2;255;255;255m\u{2580}\x1b[0m\n\
+         \x1b[38;2;1;2;3m\x1b[48;2;0;0;0m\u{2580}\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_write_frames() {
+    use crate::grid::Grid;
+
+    let dir = std::env::temp_dir().join(format!("advent2021-frames-{}", std::process::id()));
+    let frames = vec![Grid::new(2, 2, Rgb::BLACK), Grid::new(2, 2, Rgb::WHITE)];
+    write_frames(&frames, &dir, Format::Pgm).unwrap();
+    assert_eq!(
+        fs::read(dir.join("frame0001.pgm")).unwrap(),
+        b"P5\n2 2\n255\n\xff\xff\xff\xff"
+    );
+    assert!(!dir.join("frame0002.pgm").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
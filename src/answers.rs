@@ -0,0 +1,160 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+// The answers we submitted for our own inputs, so refactors can be checked
+// against more than the examples.  Kept as TOML, a table per day:
+//
+//     [day1]
+//     part1 = "1527"
+//     part2 = "1575"
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u32, u32), String>);
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Layout(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(e) => write!(f, "reading answers: {}", e),
+            AnswersError::Toml(e) => write!(f, "parsing answers: {}", e),
+            AnswersError::Layout(e) => write!(f, "answers file: {}", e),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+impl From<io::Error> for AnswersError {
+    fn from(e: io::Error) -> Self {
+        AnswersError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for AnswersError {
+    fn from(e: toml::de::Error) -> Self {
+        AnswersError::Toml(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String },
+    Unrecorded,
+}
+
+pub fn answers_path(year: u32) -> PathBuf {
+    Path::new("answers").join(format!("{}.toml", year))
+}
+
+// Rendered answers like day13 part 2 may carry trailing spaces that an editor
+// would strip from the answers file, so compare without them
+pub(crate) fn normalise(answer: &str) -> String {
+    answer
+        .trim_matches('\n')
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn number_after(key: &str, prefix: &str) -> Option<u32> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let table: toml::Table = text.parse()?;
+        let mut answers = BTreeMap::new();
+        for (key, parts) in table {
+            let day = number_after(&key, "day")
+                .ok_or_else(|| AnswersError::Layout(format!("expected [dayN], found [{}]", key)))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| AnswersError::Layout(format!("expected [{}] to be a table", key)))?;
+            for (name, value) in parts {
+                let part = number_after(name, "part").ok_or_else(|| {
+                    AnswersError::Layout(format!("expected partN in [{}], found {}", key, name))
+                })?;
+                let answer = match value {
+                    toml::Value::String(s) => normalise(s),
+                    toml::Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(AnswersError::Layout(format!(
+                            "expected {}.{} to be a string or integer",
+                            key, name
+                        )))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, part: u32, actual: &str) -> Check {
+        match self.get(day, part) {
+            None => Check::Unrecorded,
+            Some(expected) if expected == normalise(actual) => Check::Match,
+            Some(expected) => Check::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[test]
+fn test_answers() {
+    let answers = Answers::parse(
+        "[day1]\npart1 = \"7\"\npart2 = 5\n\n[day13]\npart1 = \"17\"\npart2 = '''\n# \n##'''\n",
+    )
+    .unwrap();
+    assert_eq!(answers.get(1, 2), Some("5"));
+    assert_eq!(answers.check(1, 1, "7"), Check::Match);
+    assert_eq!(
+        answers.check(1, 2, "6"),
+        Check::Mismatch {
+            expected: String::from("5")
+        }
+    );
+    assert_eq!(answers.check(2, 1, "150"), Check::Unrecorded);
+    assert_eq!(answers.check(13, 2, "# \n##"), Check::Match);
+
+    assert!(matches!(
+        Answers::parse("[one]\npart1 = 1"),
+        Err(AnswersError::Layout(_))
+    ));
+    assert!(matches!(
+        Answers::parse("[day1]\nfirst = 1"),
+        Err(AnswersError::Layout(_))
+    ));
+    assert!(matches!(
+        Answers::parse("[day1"),
+        Err(AnswersError::Toml(_))
+    ));
+}
+
+#[test]
+fn test_recorded_answers() {
+    let answers = Answers::load(answers_path(2021)).unwrap();
+    assert_eq!(answers.get(1, 1), Some("1527"));
+    assert!(answers.get(13, 2).unwrap().starts_with("#  # ####"));
+    assert_eq!(answers.get(25, 2), None);
+}
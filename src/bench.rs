@@ -0,0 +1,150 @@
+use crate::solver::Solution;
+use std::{
+    error::Error,
+    fmt::Write as _,
+    hint::black_box,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+// How one variant of a part fared over a number of runs.  The generator is
+// timed on its own, the same number of times, since every variant shares it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u32,
+    pub part: u32,
+    pub variant: &'static str,
+    pub iterations: usize,
+    pub generator: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+fn median(times: &mut [Duration]) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// `params` overrides the day's puzzle constants, see params.rs
+pub fn bench(
+    solution: &Solution,
+    input: &str,
+    params: &[(String, String)],
+    iterations: usize,
+) -> Result<Vec<Timing>, Box<dyn Error>> {
+    let iterations = iterations.max(1);
+
+    let mut generator = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations {
+        let (result, elapsed) = time(|| solution.parse(input));
+        generator.push(elapsed);
+        parsed = Some(result?);
+    }
+    let mut parsed = parsed.unwrap();
+    solution.configure(&mut parsed, params)?;
+    let generator = median(&mut generator);
+
+    let mut timings = vec![];
+    for part in 1..=2 {
+        for variant in solution.variants(part) {
+            let mut times: Vec<Duration> = (0..iterations)
+                .map(|_| time(|| black_box(solution.solve_variant(&parsed, part, variant))).1)
+                .collect();
+            let median = median(&mut times);
+            timings.push(Timing {
+                day: solution.day,
+                part,
+                variant,
+                iterations,
+                generator,
+                min: times[0],
+                median,
+                max: times[times.len() - 1],
+            });
+        }
+    }
+    Ok(timings)
+}
+
+pub fn table(timings: &[Timing]) -> String {
+    let mut out = format!(
+        "{:>3} {:>4} {:<12} {:>6} {:>12} {:>12} {:>12} {:>12}\n",
+        "day", "part", "variant", "runs", "generator", "min", "median", "max"
+    );
+    for t in timings {
+        writeln!(
+            out,
+            "{:>3} {:>4} {:<12} {:>6} {:>12} {:>12} {:>12} {:>12}",
+            t.day,
+            t.part,
+            t.variant,
+            t.iterations,
+            format!("{:.2?}", t.generator),
+            format!("{:.2?}", t.min),
+            format!("{:.2?}", t.median),
+            format!("{:.2?}", t.max),
+        )
+        .unwrap();
+    }
+    out
+}
+
+// Times in nanoseconds, to keep the CSV easy to load elsewhere
+pub fn write_csv(out: &mut impl Write, timings: &[Timing]) -> io::Result<()> {
+    writeln!(
+        out,
+        "day,part,variant,iterations,generator_ns,min_ns,median_ns,max_ns"
+    )?;
+    for t in timings {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            t.day,
+            t.part,
+            t.variant,
+            t.iterations,
+            t.generator.as_nanos(),
+            t.min.as_nanos(),
+            t.median.as_nanos(),
+            t.max.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bench() {
+    use crate::solver::lookup;
+
+    let day22 = lookup(2021, 22).unwrap();
+    let timings = bench(day22, day22.example.unwrap(), &[], 3).unwrap();
+    assert_eq!(
+        timings
+            .iter()
+            .map(|t| (t.part, t.variant))
+            .collect::<Vec<_>>(),
+        vec![(1, "default"), (1, "slicing"), (2, "default")]
+    );
+    assert!(timings
+        .iter()
+        .all(|t| t.iterations == 3 && t.min <= t.median && t.median <= t.max));
+    assert_eq!(table(&timings).lines().count(), 4);
+
+    let mut csv = vec![];
+    write_csv(&mut csv, &timings).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("day,part,variant,iterations,"));
+    assert!(csv.lines().nth(2).unwrap().starts_with("22,1,slicing,3,"));
+
+    assert!(bench(day22, "on x=1", &[], 3).is_err());
+    let steps = [(String::from("steps"), String::from("10"))];
+    assert!(bench(day22, day22.example.unwrap(), &steps, 3).is_err());
+}
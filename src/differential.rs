@@ -0,0 +1,215 @@
+use crate::parallel::panic_message;
+use crate::solver::{Solution, DEFAULT_VARIANT};
+use std::{
+    fmt,
+    ops::Range,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+// Two ways of working something out which ought to agree, tried against each
+// other on generated inputs.  When they don't, the input is shrunk for as long
+// as they still disagree, so what gets reported is small enough to follow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<T, R> {
+    pub seed: u64,
+    pub input: T,
+    pub left: Outcome<R>,
+    pub right: Outcome<R>,
+}
+
+// What an implementation came up with, or what it said as it panicked
+pub type Outcome<R> = Result<R, String>;
+
+impl<T: fmt::Debug, R: fmt::Debug> fmt::Display for Mismatch<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "seed {} shrank to input {:#?}", self.seed, self.input)?;
+        writeln!(f, "  left:  {:?}", self.left)?;
+        write!(f, "  right: {:?}", self.right)
+    }
+}
+
+fn outcome<T, R>(f: &impl Fn(&T) -> R, input: &T) -> Outcome<R> {
+    catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|panic| panic_message(panic.as_ref()))
+}
+
+pub fn compare<T: Clone, R: PartialEq>(
+    seeds: Range<u64>,
+    generate: impl Fn(u64) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    left: impl Fn(&T) -> R,
+    right: impl Fn(&T) -> R,
+) -> Result<(), Mismatch<T, R>> {
+    let differs = |input: &T| {
+        let (l, r) = (outcome(&left, input), outcome(&right, input));
+        (l != r).then_some((l, r))
+    };
+
+    for seed in seeds {
+        let mut input = generate(seed);
+        let mut found = match differs(&input) {
+            Some(found) => found,
+            None => continue,
+        };
+        // Take the first smaller input that still shows the difference, until
+        // there are none
+        'shrinking: loop {
+            for candidate in shrink(&input) {
+                if let Some(smaller) = differs(&candidate) {
+                    input = candidate;
+                    found = smaller;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        let (left, right) = found;
+        return Err(Mismatch {
+            seed,
+            input,
+            left,
+            right,
+        });
+    }
+    Ok(())
+}
+
+// compare, for tests, failing with the shrunk input when the two disagree
+#[track_caller]
+pub fn assert_agree<T: Clone + fmt::Debug, R: PartialEq + fmt::Debug>(
+    seeds: Range<u64>,
+    generate: impl Fn(u64) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    left: impl Fn(&T) -> R,
+    right: impl Fn(&T) -> R,
+) {
+    if let Err(mismatch) = compare(seeds, generate, shrink, left, right) {
+        panic!("{}", mismatch);
+    }
+}
+
+// Smaller lists, from dropping halves down to dropping one item at a time
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut smaller = vec![];
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            smaller.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    smaller
+}
+
+// Inputs with lines left out, for the days read a line at a time
+pub fn shrink_lines(input: &str) -> Vec<String> {
+    shrink_vec(&input.lines().collect::<Vec<_>>())
+        .into_iter()
+        .map(|lines| lines.join("\n"))
+        .collect()
+}
+
+// The part and variant which disagreed with the default, and how
+pub type VariantMismatch = (u32, &'static str, Mismatch<String, Option<String>>);
+
+// Every variant of a part against the default, on inputs of about `size`
+// made by Solution::random, so a seed here is a seed for --generate too.
+// Shrunk inputs which no longer parse agree with themselves, so are passed by.
+pub fn variants(
+    solution: &Solution,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<(), VariantMismatch> {
+    for part in 1..=solution.parts {
+        for variant in solution.variants(part).filter(|&v| v != DEFAULT_VARIANT) {
+            let run = |name: &'static str| {
+                move |input: &String| {
+                    let parsed = solution.parse(input).ok()?;
+                    solution.solve_variant(&parsed, part, name)
+                }
+            };
+            compare(
+                seeds.clone(),
+                |seed| solution.random(seed, size),
+                |input| shrink_lines(input),
+                run(DEFAULT_VARIANT),
+                run(variant),
+            )
+            .map_err(|mismatch| (part, variant, mismatch))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_compare() {
+    // Agreeing on everything but multiples of seven
+    let result = compare(
+        0..20,
+        |seed| {
+            use rand::Rng;
+            let mut rng = crate::random::seeded(seed);
+            (0..20).map(|_| rng.gen_range(0..100)).collect::<Vec<u32>>()
+        },
+        |v| shrink_vec(v),
+        |v| v.iter().sum::<u32>(),
+        |v| v.iter().filter(|&&n| n % 7 != 0).sum::<u32>(),
+    );
+    let mismatch = result.unwrap_err();
+    assert_eq!(mismatch.input.len(), 1);
+    assert_eq!(mismatch.input[0] % 7, 0);
+    assert_ne!(mismatch.input[0], 0);
+
+    let panics = compare(
+        0..1,
+        |_| vec![1, 2, 3],
+        |v| shrink_vec(v),
+        |v| v.len(),
+        |v| {
+            if v.contains(&2) {
+                panic!("two")
+            } else {
+                v.len()
+            }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(panics.input, vec![2]);
+    assert_eq!(panics.right, Err(String::from("two")));
+
+    assert!(compare(0..5, |_| 1, |_| vec![], |n| n + 1, |n| 1 + n).is_ok());
+}
+
+#[test]
+fn test_shrink_vec() {
+    assert_eq!(
+        shrink_vec(&[1, 2, 3, 4]),
+        vec![
+            vec![3, 4],
+            vec![1, 2],
+            vec![2, 3, 4],
+            vec![1, 3, 4],
+            vec![1, 2, 4],
+            vec![1, 2, 3]
+        ]
+    );
+    assert!(shrink_vec::<u32>(&[]).is_empty());
+    assert_eq!(
+        shrink_lines("a\nb"),
+        vec![String::from("b"), String::from("a")]
+    );
+}
+
+#[test]
+fn test_variants_agree() {
+    use crate::solver::solutions;
+
+    for solution in solutions() {
+        if let Err((part, variant, mismatch)) = variants(solution, 0..5, 20) {
+            panic!(
+                "day {} part {} {} drifted from the default\n{}",
+                solution.day, part, variant, mismatch
+            );
+        }
+    }
+}
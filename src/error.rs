@@ -0,0 +1,257 @@
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+// Where a puzzle input stopped making sense, and what we hoped to find there.
+// Lines and columns count from 1, like an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(day: u32, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // An error on a single line of input, at `token` which is a slice of `text`
+    pub fn at(day: u32, text: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(day, 1, column_of(text, token), expected)
+    }
+
+    // Errors from line parsers count from line 1; move them to where that line was
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// The 1-based column where `token` starts, when it was sliced out of `text`.
+// Anything else, most often the empty remainder of a line, points past the end.
+pub fn column_of(text: &str, token: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let at = token.as_ptr() as usize;
+    if at >= start && at <= start + text.len() {
+        at - start + 1
+    } else {
+        text.len() + 1
+    }
+}
+
+pub fn number<T: FromStr>(day: u32, text: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(day, text, token, "a number"))
+}
+
+// A line of single digit numbers, like the heightmaps and octopus grids
+pub fn digits(day: u32, line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(d) => Ok(d as u8),
+            None => Err(ParseError::at(day, line, &line[i..], "a digit")),
+        })
+        .collect()
+}
+
+// Lines of a grid should all be as wide as the first
+pub fn same_width(day: u32, width: usize, line: &str) -> Result<(), ParseError> {
+    if line.len() == width {
+        Ok(())
+    } else {
+        let column = line.len().min(width) + 1;
+        Err(ParseError::new(
+            day,
+            1,
+            column,
+            format!("{} columns", width),
+        ))
+    }
+}
+
+// Parses each line of the input with `parse`, numbering any error with its line
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(n, line)| parse(line).map_err(|e| e.on_line(n + 1)))
+        .collect()
+}
+
+// What can go wrong with an input read as it streams in, rather than whole
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "reading input: {}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+// The lines of a reader, leaving off blank lines at the end the same as
+// Solution::parse does.  Blank lines are held back until something follows.
+struct Lines<R> {
+    lines: io::Lines<R>,
+    blanks: usize,
+    held: Option<String>,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.blanks > 0 {
+            self.blanks -= 1;
+            return Some(Ok(String::new()));
+        }
+        if let Some(line) = self.held.take() {
+            return Some(Ok(line));
+        }
+        loop {
+            match self.lines.next()? {
+                Ok(line) if line.is_empty() => self.blanks += 1,
+                Ok(line) if self.blanks > 0 => {
+                    self.held = Some(line);
+                    return self.next();
+                }
+                other => return Some(other),
+            }
+        }
+    }
+}
+
+// parse_lines for a reader, parsing each line as it's read so the input need
+// never be held whole
+pub fn stream_lines<T>(
+    reader: impl BufRead,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> impl Iterator<Item = Result<T, StreamError>> {
+    let lines = Lines {
+        lines: reader.lines(),
+        blanks: 0,
+        held: None,
+    };
+    lines.enumerate().map(move |(n, line)| {
+        let line = line?;
+        Ok(parse(&line).map_err(|e| e.on_line(n + 1))?)
+    })
+}
+
+#[test]
+fn test_parse_error_display() {
+    assert_eq!(
+        ParseError::new(2, 3, 9, "a number").to_string(),
+        "day 2 input, line 3, column 9: expected a number"
+    );
+}
+
+#[test]
+fn test_column_of() {
+    let text = "forward 5";
+    assert_eq!(column_of(text, &text[0..7]), 1);
+    assert_eq!(column_of(text, &text[8..]), 9);
+    assert_eq!(column_of(text, &text[9..]), 10);
+    assert_eq!(column_of(text, "elsewhere"), 10);
+}
+
+#[test]
+fn test_parse_lines() {
+    assert_eq!(
+        parse_lines("1\n2\n3", |l| number::<u32>(1, l, l)),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        parse_lines("1\n2\nx3", |l| number::<u32>(1, l, l)),
+        Err(ParseError::new(1, 3, 1, "a number"))
+    );
+}
+
+#[test]
+fn test_stream_lines() {
+    let stream = |input: &str| {
+        stream_lines(input.as_bytes(), |l| number::<u32>(1, l, l))
+            .map(|n| n.map_err(|e| e.to_string()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(stream("1\n2\n3\n\n"), [Ok(1), Ok(2), Ok(3)]);
+    assert_eq!(
+        stream("1\n\nx3"),
+        [
+            Ok(1),
+            Err(String::from(
+                "day 1 input, line 2, column 1: expected a number"
+            )),
+            Err(String::from(
+                "day 1 input, line 3, column 1: expected a number"
+            ))
+        ]
+    );
+    assert!(stream("").is_empty());
+
+    let lines = |input: &str| {
+        stream_lines(input.as_bytes(), |l| Ok(l.to_string()))
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(lines("a\n\n\nb\n\n"), ["a", "", "", "b"]);
+    assert_eq!(
+        lines("a\n\nb"),
+        parse_lines("a\n\nb", |l| Ok(l.to_string())).unwrap()
+    );
+}
+
+#[test]
+fn test_digits() {
+    assert_eq!(digits(9, "2199"), Ok(vec![2, 1, 9, 9]));
+    assert_eq!(digits(9, "21a9"), Err(ParseError::new(9, 1, 3, "a digit")));
+    assert_eq!(
+        same_width(9, 4, "21999"),
+        Err(ParseError::new(9, 1, 5, "4 columns"))
+    );
+}
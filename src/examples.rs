@@ -0,0 +1,351 @@
+use crate::answers::{normalise, AnswersError};
+use crate::parallel::panic_message;
+use crate::params::setting;
+use crate::solver::Solution;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+// Example inputs are kept as files beside the days they're for, like
+// src/y2021/day22_example_2.txt, each paired with a TOML file of the answers
+// the puzzle text gives for it, day22_example_2.toml:
+//
+//     part1 = 474140
+//     part2 = "2758514936282235"
+//
+// A part set to false has no answer for that example, and parts listed as
+// slow = [2] are only checked when asked.  A [params] table overrides the
+// day's constants, the same as in params.rs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    Nothing,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expectations {
+    pub parts: BTreeMap<u32, Expected>,
+    pub slow: Vec<u32>,
+    pub params: Vec<(String, String)>,
+}
+
+impl Expectations {
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let table: toml::Table = text.parse()?;
+        let mut expectations = Expectations::default();
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("slow", toml::Value::Array(parts)) => {
+                    for part in parts {
+                        let part = part.as_integer().and_then(|p| u32::try_from(p).ok());
+                        let part = part.ok_or_else(|| {
+                            AnswersError::Layout(String::from("expected slow to list parts"))
+                        })?;
+                        expectations.slow.push(part);
+                    }
+                }
+                ("params", toml::Value::Table(params)) => {
+                    for (name, value) in params {
+                        expectations.params.push((name, setting(&value)));
+                    }
+                }
+                (name, value) => {
+                    let part = name
+                        .strip_prefix("part")
+                        .and_then(|n| n.parse().ok())
+                        .ok_or_else(|| {
+                            AnswersError::Layout(format!(
+                                "expected partN, slow or [params], found {}",
+                                name
+                            ))
+                        })?;
+                    let expected = match value {
+                        toml::Value::String(s) => Expected::Answer(normalise(&s)),
+                        toml::Value::Integer(n) => Expected::Answer(n.to_string()),
+                        toml::Value::Boolean(false) => Expected::Nothing,
+                        _ => {
+                            return Err(AnswersError::Layout(format!(
+                                "expected {} to be an answer or false",
+                                name
+                            )))
+                        }
+                    };
+                    expectations.parts.insert(part, expected);
+                }
+            }
+        }
+        Ok(expectations)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub day: u32,
+    pub input: String,
+    // None until someone writes down what the answers should be
+    pub expectations: Option<Expectations>,
+}
+
+// Where a year's days, and so their examples, live: under `root` when we're
+// told, otherwise in src/ of the checkout we're run from, and failing that the
+// one we were built from, which an installed copy may well have outlived
+pub fn examples_dir(root: Option<&Path>, year: u32) -> PathBuf {
+    let year = format!("y{}", year);
+    if let Some(root) = root {
+        return root.join(year);
+    }
+    let here = Path::new("src").join(&year);
+    if here.is_dir() {
+        return here;
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(year)
+}
+
+lazy_static! {
+    static ref EXAMPLE_FILE: Regex = Regex::new(r"^day(\d+)_(.*)\.txt$").unwrap();
+}
+
+// Every dayN_*.txt in `dir`, in order of day
+pub fn discover(dir: &Path) -> Result<Vec<Example>, AnswersError> {
+    let mut examples = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(file) = path.file_name().and_then(|f| f.to_str()) else {
+            continue;
+        };
+        let Some(day) = EXAMPLE_FILE.captures(file).and_then(|c| c[1].parse().ok()) else {
+            continue;
+        };
+        let sidecar = path.with_extension("toml");
+        let expectations = if sidecar.exists() {
+            let text = fs::read_to_string(&sidecar)?;
+            let expectations = Expectations::parse(&text).map_err(|e| match e {
+                AnswersError::Layout(e) => AnswersError::Layout(format!("{}: {}", file, e)),
+                e => e,
+            })?;
+            Some(expectations)
+        } else {
+            None
+        };
+        examples.push(Example {
+            name: file.trim_end_matches(".txt").to_string(),
+            day,
+            input: fs::read_to_string(&path)?,
+            expectations,
+        });
+    }
+    examples.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    Ok(examples)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch { expected: String, actual: String },
+    Failed(String),
+    NoAnswer,
+    Slow,
+    // Nothing written down for this part yet, but here's what we make it
+    Unexpected(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    pub example: String,
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Outcome,
+}
+
+fn solve(solution: &Solution, example: &Example, part: u32) -> Result<String, String> {
+    let params = example
+        .expectations
+        .as_ref()
+        .map_or(&[][..], |e| e.params.as_slice());
+    catch_unwind(AssertUnwindSafe(|| {
+        let mut parsed = solution.parse(&example.input).map_err(|e| e.to_string())?;
+        solution
+            .configure(&mut parsed, params)
+            .map_err(|e| e.to_string())?;
+        solution
+            .solve(&parsed, part)
+            .ok_or_else(|| format!("no part {}", part))
+    }))
+    .unwrap_or_else(|panic| Err(format!("panicked: {}", panic_message(panic.as_ref()))))
+}
+
+// Runs each part of the example the puzzle has, leaving out the slow ones
+// unless `slow` is set
+pub fn check(solution: &Solution, example: &Example, slow: bool) -> Vec<Checked> {
+    (1..=solution.parts)
+        .map(|part| {
+            let expectations = example.expectations.as_ref();
+            let expected = expectations.and_then(|e| e.parts.get(&part));
+            let outcome = match expected {
+                Some(Expected::Nothing) => Outcome::NoAnswer,
+                _ if !slow && expectations.is_some_and(|e| e.slow.contains(&part)) => Outcome::Slow,
+                Some(Expected::Answer(expected)) => match solve(solution, example, part) {
+                    Ok(actual) if normalise(&actual) == *expected => Outcome::Match,
+                    Ok(actual) => Outcome::Mismatch {
+                        expected: expected.clone(),
+                        actual,
+                    },
+                    Err(e) => Outcome::Failed(e),
+                },
+                None => match solve(solution, example, part) {
+                    Ok(actual) => Outcome::Unexpected(actual),
+                    Err(e) => Outcome::Failed(e),
+                },
+            };
+            Checked {
+                example: example.name.clone(),
+                day: example.day,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn passed(checked: &[Checked]) -> bool {
+    checked
+        .iter()
+        .all(|c| !matches!(c.outcome, Outcome::Mismatch { .. } | Outcome::Failed(_)))
+}
+
+// A line per part, then the parts still wanting an expected answer
+pub fn report(checked: &[Checked]) -> String {
+    let mut out = format!(
+        "{:>3} {:>4} {:<24} {}\n",
+        "day", "part", "example", "outcome"
+    );
+    for c in checked {
+        let outcome = match &c.outcome {
+            Outcome::Match => String::from("ok"),
+            Outcome::Mismatch { expected, actual } => {
+                format!("MISMATCH, expected {} but got {}", expected, actual)
+            }
+            Outcome::Failed(e) => format!("FAILED, {}", e),
+            Outcome::NoAnswer => String::from("no answer for this example"),
+            Outcome::Slow => String::from("slow, skipped"),
+            Outcome::Unexpected(actual) => format!("{} (no expectation)", actual),
+        };
+        writeln!(
+            out,
+            "{:>3} {:>4} {:<24} {}",
+            c.day, c.part, c.example, outcome
+        )
+        .unwrap();
+    }
+
+    let missing: Vec<_> = checked
+        .iter()
+        .filter(|c| matches!(c.outcome, Outcome::Unexpected(_)))
+        .map(|c| format!("{} part {}", c.example, c.part))
+        .collect();
+    if missing.is_empty() {
+        out.push_str("Every example has its answers\n");
+    } else {
+        writeln!(out, "No expected answer yet for {}", missing.join(", ")).unwrap();
+    }
+    out
+}
+
+#[test]
+fn test_expectations() {
+    let expectations =
+        Expectations::parse("part1 = 6\npart2 = false\nslow = [2]\n\n[params]\noverlap = 6\n")
+            .unwrap();
+    assert_eq!(
+        expectations,
+        Expectations {
+            parts: BTreeMap::from([
+                (1, Expected::Answer(String::from("6"))),
+                (2, Expected::Nothing)
+            ]),
+            slow: vec![2],
+            params: vec![(String::from("overlap"), String::from("6"))],
+        }
+    );
+    assert!(matches!(
+        Expectations::parse("answer = 1"),
+        Err(AnswersError::Layout(_))
+    ));
+    assert!(matches!(
+        Expectations::parse("part1 = true"),
+        Err(AnswersError::Layout(_))
+    ));
+}
+
+#[test]
+fn test_check() {
+    use crate::solver::lookup;
+
+    let dir = std::env::temp_dir().join(format!("advent2021-examples-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let example = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+    fs::write(dir.join("day1_example.txt"), example).unwrap();
+    fs::write(dir.join("day1_larger.txt"), example).unwrap();
+    fs::write(dir.join("day1_larger.toml"), "part1 = 7\npart2 = 6\n").unwrap();
+    fs::write(dir.join("notes.txt"), "not an example").unwrap();
+
+    assert_eq!(examples_dir(Some(&dir), 2021), dir.join("y2021"));
+    let examples = discover(&dir).unwrap();
+    assert_eq!(
+        examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(),
+        vec!["day1_example", "day1_larger"]
+    );
+    let day1 = lookup(2021, 1).unwrap();
+    let checked: Vec<_> = examples
+        .iter()
+        .flat_map(|example| check(day1, example, false))
+        .collect();
+    assert_eq!(
+        checked.iter().map(|c| &c.outcome).collect::<Vec<_>>(),
+        vec![
+            &Outcome::Unexpected(String::from("7")),
+            &Outcome::Unexpected(String::from("5")),
+            &Outcome::Match,
+            &Outcome::Mismatch {
+                expected: String::from("6"),
+                actual: String::from("5")
+            }
+        ]
+    );
+    assert!(!passed(&checked));
+    assert!(report(&checked)
+        .ends_with("No expected answer yet for day1_example part 1, day1_example part 2\n"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(test)]
+fn check_year(year: u32, slow: bool) {
+    use crate::solver::lookup;
+
+    let checked: Vec<_> = discover(&examples_dir(None, year))
+        .unwrap()
+        .iter()
+        .flat_map(|example| check(lookup(year, example.day).unwrap(), example, slow))
+        .collect();
+    assert!(!checked.is_empty());
+    assert!(passed(&checked), "{}", report(&checked));
+}
+
+#[test]
+fn test_examples() {
+    check_year(2021, false);
+}
+
+#[ignore] // Day 23's part 2 example takes minutes
+#[test]
+fn test_slow_examples() {
+    check_year(2021, true);
+}
@@ -0,0 +1,251 @@
+use crate::runner::{input_hash, input_path};
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+// Downloads the inputs missing from input/, so nobody has to copy 25 files
+// in by hand.  Advent of Code only serves an input to the account it was
+// made for, so requests carry the session cookie of a logged in browser.
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".aoc-session";
+
+// Advent of Code asks that automated requests say where they come from
+const USER_AGENT: &str = "advent2021 input fetcher";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    Http(String),
+    Io(io::Error),
+    Changed(PathBuf),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session token, set {} or save it in {}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            FetchError::Http(e) => write!(f, "fetching input: {}", e),
+            FetchError::Io(e) => write!(f, "saving input: {}", e),
+            FetchError::Changed(path) => write!(
+                f,
+                "{} has changed since it was fetched, delete it to fetch it again",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for FetchError {
+    fn from(e: ureq::Error) -> Self {
+        FetchError::Http(e.to_string())
+    }
+}
+
+// The session token from the environment when it's set there, otherwise from
+// the file, if there is one
+pub fn session(var: Option<String>, file: &Path) -> io::Result<Option<String>> {
+    let token = match var {
+        Some(token) => token,
+        None => match fs::read_to_string(file) {
+            Ok(token) => token,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        },
+    };
+    let token = token.trim();
+    Ok((!token.is_empty()).then(|| token.to_string()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+pub struct Fetcher {
+    pub base_url: String,
+    // Where the inputs are kept, as <root>/<year>/day<N>.txt
+    pub root: PathBuf,
+    // Only needed for inputs we don't have yet
+    pub session: Option<String>,
+}
+
+// Beside each fetched input is the hash it had when it arrived, so an input
+// edited since can't pass for the real thing
+fn hash_path(path: &Path) -> PathBuf {
+    path.with_extension("txt.fnv1a")
+}
+
+impl Fetcher {
+    pub fn new(session: Option<String>) -> Self {
+        Fetcher {
+            base_url: BASE_URL.to_string(),
+            root: PathBuf::from("input"),
+            session,
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        input_path(&self.root, year, day)
+    }
+
+    // Makes sure there's a copy of the day's input, only ever asking for it
+    // when there isn't one.  Copies put there by hand have no hash to check.
+    pub fn get(&self, year: u32, day: u32) -> Result<Fetched, FetchError> {
+        let path = self.path(year, day);
+        if path.exists() {
+            if let Ok(hash) = fs::read_to_string(hash_path(&path)) {
+                if hash.trim() != input_hash(&fs::read_to_string(&path)?) {
+                    return Err(FetchError::Changed(path));
+                }
+            }
+            return Ok(Fetched::Cached);
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::NoSession)?;
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let input = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call()?
+            .into_string()?;
+
+        // Written aside and moved into place, so a download cut short never
+        // looks like a cached input.  The hash goes in last, as an input
+        // without one is only taken on trust, where a stale one would fail.
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, &input)?;
+        fs::rename(&partial, &path)?;
+        fs::write(hash_path(&path), format!("{}\n", input_hash(&input)))?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[test]
+fn test_session() {
+    let dir = std::env::temp_dir().join(format!("advent2021-session-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join(SESSION_FILE);
+
+    assert_eq!(session(None, &file).unwrap(), None);
+    fs::write(&file, "abc123\n").unwrap();
+    assert_eq!(session(None, &file).unwrap(), Some(String::from("abc123")));
+    assert_eq!(
+        session(Some(String::from("from-env")), &file).unwrap(),
+        Some(String::from("from-env"))
+    );
+    assert_eq!(session(Some(String::from(" ")), &file).unwrap(), None);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_fetch() {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    // Stands in for adventofcode.com, serving day 1 to the right session
+    // only, for as many requests as the test should make
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests = vec![];
+        for stream in listener.incoming().take(2) {
+            let mut stream = stream.unwrap();
+            let mut headers = vec![];
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                headers.push(line.trim_end().to_string());
+            }
+            let authorised = headers.iter().any(|h| h == "Cookie: session=s3cret");
+            let (status, body) = match headers[0].as_str() {
+                "GET /2021/day/1/input HTTP/1.1" if authorised => ("200 OK", "199\n200\n208\n"),
+                _ if !authorised => ("400 Bad Request", "Puzzle inputs differ by user."),
+                _ => ("404 Not Found", "Not found"),
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            requests.push(headers[0].clone());
+        }
+        requests
+    });
+
+    let root = std::env::temp_dir().join(format!("advent2021-fetch-{}", std::process::id()));
+    let mut fetcher = Fetcher::new(Some(String::from("s3cret")));
+    fetcher.base_url = base_url;
+    fetcher.root = root.clone();
+
+    let path = fetcher.path(2021, 1);
+    assert_eq!(path, root.join("2021").join("day1.txt"));
+    assert_eq!(fetcher.get(2021, 1).unwrap(), Fetched::Downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "199\n200\n208\n");
+    assert_eq!(
+        fs::read_to_string(hash_path(&path)).unwrap().trim(),
+        input_hash("199\n200\n208\n")
+    );
+    // Never asked for again once we have it
+    assert_eq!(fetcher.get(2021, 1).unwrap(), Fetched::Cached);
+
+    fs::write(&path, "199\n200\n").unwrap();
+    assert!(matches!(
+        fetcher.get(2021, 1),
+        Err(FetchError::Changed(changed)) if changed == path
+    ));
+
+    fetcher.session = Some(String::from("expired"));
+    assert!(matches!(fetcher.get(2021, 2), Err(FetchError::Http(_))));
+    assert!(!fetcher.path(2021, 2).exists());
+
+    fetcher.session = None;
+    assert!(matches!(fetcher.get(2021, 3), Err(FetchError::NoSession)));
+
+    // A copy put there by hand is taken as it is
+    fs::write(fetcher.path(2021, 4), "4\n").unwrap();
+    assert_eq!(fetcher.get(2021, 4).unwrap(), Fetched::Cached);
+
+    assert_eq!(
+        server.join().unwrap(),
+        vec![
+            "GET /2021/day/1/input HTTP/1.1",
+            "GET /2021/day/2/input HTTP/1.1"
+        ]
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,325 @@
+use lazy_static::lazy_static;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+// Integer vectors, for positions and the offsets between them
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+// An inclusive box around some points, from its smallest corner to its largest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<V> {
+    pub min: V,
+    pub max: V,
+}
+
+macro_rules! vector {
+    ($name:ident { $($f:ident),+ }) => {
+        impl $name {
+            pub const ZERO: $name = $name { $($f: 0),+ };
+
+            pub const fn new($($f: i64),+) -> Self {
+                $name { $($f),+ }
+            }
+
+            pub fn abs(self) -> Self {
+                $name { $($f: self.$f.abs()),+ }
+            }
+
+            pub fn signum(self) -> Self {
+                $name { $($f: self.$f.signum()),+ }
+            }
+
+            // Componentwise, so the corners of a bounding box
+            pub fn min(self, other: Self) -> Self {
+                $name { $($f: self.$f.min(other.$f)),+ }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $name { $($f: self.$f.max(other.$f)),+ }
+            }
+
+            // Taxicab distance, moving along one axis at a time
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$f - other.$f).abs())+
+            }
+
+            // King's move distance, where diagonal steps count as one
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0i64 $(.max((self.$f - other.$f).abs()))+
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                $name { $($f: self.$f + rhs.$f),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                $name { $($f: self.$f - rhs.$f),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                $name { $($f: -self.$f),+ }
+            }
+        }
+
+        impl Mul<i64> for $name {
+            type Output = Self;
+            fn mul(self, rhs: i64) -> Self {
+                $name { $($f: self.$f * rhs),+ }
+            }
+        }
+
+        impl Div<i64> for $name {
+            type Output = Self;
+            fn div(self, rhs: i64) -> Self {
+                $name { $($f: self.$f / rhs),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<i64> for $name {
+            fn mul_assign(&mut self, rhs: i64) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Vector for $name {
+            fn splat(n: i64) -> Self {
+                $name { $($f: n),+ }
+            }
+
+            fn min(self, other: Self) -> Self {
+                $name::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $name::max(self, other)
+            }
+
+            fn all_le(self, other: Self) -> bool {
+                true $(&& self.$f <= other.$f)+
+            }
+        }
+    };
+}
+
+vector!(Vec2 { x, y });
+vector!(Vec3 { x, y, z });
+
+// What Bounds needs of Vec2 and Vec3
+pub trait Vector: Copy + Add<Output = Self> + Sub<Output = Self> {
+    fn splat(n: i64) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    // Whether every component is no more than that of `other`
+    fn all_le(self, other: Self) -> bool;
+}
+
+impl<V: Vector> Bounds<V> {
+    pub fn new(a: V, b: V) -> Self {
+        Bounds {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    // The smallest box holding every point, if there are any
+    pub fn of(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Bounds::new(first, first), |b, p| b.including(p)))
+    }
+
+    pub fn including(self, p: V) -> Self {
+        Bounds {
+            min: self.min.min(p),
+            max: self.max.max(p),
+        }
+    }
+
+    pub fn contains(&self, p: V) -> bool {
+        self.min.all_le(p) && p.all_le(self.max)
+    }
+
+    // How many points along each axis
+    pub fn size(&self) -> V {
+        self.max - self.min + V::splat(1)
+    }
+}
+
+impl From<(i64, i64)> for Vec2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl From<(i64, i64, i64)> for Vec3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// A 3x3 integer matrix, row by row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mat3(pub [[i64; 3]; 3]);
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    pub fn determinant(&self) -> i64 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    fn mul(self, v: Vec3) -> Vec3 {
+        let row = |r: [i64; 3]| r[0] * v.x + r[1] * v.y + r[2] * v.z;
+        Vec3::new(row(self.0[0]), row(self.0[1]), row(self.0[2]))
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Mat3;
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        let mut m = [[0; 3]; 3];
+        for (r, row) in m.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.0[r][k] * rhs.0[k][c]).sum();
+            }
+        }
+        Mat3(m)
+    }
+}
+
+lazy_static! {
+    // The 24 ways to turn something to face along an axis, with either side
+    // up.  Every axis goes to some axis, either way round, so of the 48 signed
+    // permutations it is those which are not mirror images.  Identity first.
+    pub static ref ROTATIONS: Vec<Mat3> = {
+        let axes = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let signs = [1, -1];
+        let mut rotations = vec![];
+        for axis in axes {
+            for (&sx, &sy, &sz) in itertools::iproduct!(&signs, &signs, &signs) {
+                let mut m = [[0; 3]; 3];
+                for (row, (a, s)) in axis.iter().zip([sx, sy, sz]).enumerate() {
+                    m[row][*a] = s;
+                }
+                let m = Mat3(m);
+                if m.determinant() == 1 {
+                    rotations.push(m);
+                }
+            }
+        }
+        rotations
+    };
+}
+
+#[test]
+fn test_vector_arithmetic() {
+    let a = Vec2::new(3, -4);
+    let b = Vec2::new(-1, 2);
+    assert_eq!(a + b, Vec2::new(2, -2));
+    assert_eq!(a - b, Vec2::new(4, -6));
+    assert_eq!(-a, Vec2::new(-3, 4));
+    assert_eq!(a * 2, Vec2::new(6, -8));
+    assert_eq!(a / 2, Vec2::new(1, -2));
+    assert_eq!(a.signum(), Vec2::new(1, -1));
+    assert_eq!(a.abs(), Vec2::new(3, 4));
+
+    let mut c = a;
+    c += b;
+    c -= Vec2::new(1, 1);
+    c *= 3;
+    assert_eq!(c, Vec2::new(3, -9));
+
+    assert_eq!(a.manhattan(b), 10);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!(Vec3::ZERO.manhattan(Vec3::new(-100, 100, 200)), 400);
+    assert_eq!(Vec3::ZERO.chebyshev(Vec3::new(-100, 100, 200)), 200);
+}
+
+#[test]
+fn test_bounds() {
+    let bounds = Bounds::of([(3, 1), (0, 4), (2, 2)].map(Vec2::from)).unwrap();
+    assert_eq!(bounds.min, Vec2::new(0, 1));
+    assert_eq!(bounds.max, Vec2::new(3, 4));
+    assert_eq!(bounds.size(), Vec2::new(4, 4));
+    assert!(bounds.contains(Vec2::new(3, 4)));
+    assert!(!bounds.contains(Vec2::new(3, 5)));
+    assert_eq!(Bounds::<Vec3>::of([]), None);
+    assert_eq!(
+        Bounds::new(Vec3::new(1, 5, -1), Vec3::new(0, 0, 0)),
+        Bounds {
+            min: Vec3::new(0, 0, -1),
+            max: Vec3::new(1, 5, 0)
+        }
+    );
+}
+
+#[test]
+fn test_rotations() {
+    use std::collections::HashSet;
+
+    assert_eq!(ROTATIONS.len(), 24);
+    assert_eq!(ROTATIONS[0], Mat3::IDENTITY);
+
+    let p = Vec3::new(1, 2, 3);
+    let turned: HashSet<Vec3> = ROTATIONS.iter().map(|&r| r * p).collect();
+    assert_eq!(turned.len(), 24);
+
+    // A group, closed under composition
+    for &a in ROTATIONS.iter() {
+        for &b in ROTATIONS.iter() {
+            assert!(ROTATIONS.contains(&(a * b)));
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use crate::error::{digits, same_width, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+// A rectangle of cells addressed as (x, y), x across and y down from the top
+// left, like the puzzle text draws them.  Lookups outside the rectangle give
+// the background, when there is one, for maps that go on forever.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    background: Option<T>,
+}
+
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_cells(width, height, vec![fill; width * height])
+    }
+
+    // Grows the grid by `by` cells on every side, filled with `fill`
+    pub fn pad(&self, by: usize, fill: T) -> Self {
+        let mut grid = Grid::new(self.width + 2 * by, self.height + 2 * by, fill);
+        grid.background = self.background.clone();
+        for ((x, y), cell) in self.indexed_iter() {
+            grid[(x + by, y + by)] = cell.clone();
+        }
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "{}x{} grid", width, height);
+        Grid {
+            width,
+            height,
+            cells,
+            background: None,
+        }
+    }
+
+    // Parses one cell per character, with `cell` rejecting any it does not
+    // know by returning None, reported as wanting `expected`
+    pub fn parse(
+        day: u32,
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |l| l.len());
+        let mut cells = vec![];
+        let mut height = 0;
+        for (n, line) in input.lines().enumerate() {
+            same_width(day, width, line).map_err(|e| e.on_line(n + 1))?;
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(ParseError::at(day, line, &line[i..], expected).on_line(n + 1))
+                    }
+                }
+            }
+            height += 1;
+        }
+        Ok(Self::from_cells(width, height, cells))
+    }
+
+    pub fn with_background(mut self, background: T) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    pub fn background(&self) -> Option<&T> {
+        self.background.as_ref()
+    }
+
+    pub fn set_background(&mut self, background: T) {
+        self.background = Some(background);
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    // The cell at (x, y), or the background if that is off the grid
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self[(x as usize, y as usize)])
+        } else {
+            self.background.as_ref()
+        }
+    }
+
+    // Where (x, y) lands when the grid repeats in every direction
+    pub fn wrap(&self, x: isize, y: isize) -> (usize, usize) {
+        (
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    pub fn wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    fn offsets(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.contains(x, y).then_some((x as usize, y as usize))
+        })
+    }
+
+    // Up, left, right and down of (x, y), where they are on the grid
+    pub fn neighbours4(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(at, &NEIGHBOURS4)
+    }
+
+    // Every cell touching (x, y), diagonals included, reading order
+    pub fn neighbours8(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(at, &NEIGHBOURS8)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&f).collect(),
+            background: self.background.as_ref().map(f),
+        }
+    }
+
+    // Draws the grid a character per cell, a line per row
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Grid<u8> {
+    // The maps of single digit numbers, like heights and risk levels
+    pub fn parse_digits(day: u32, input: &str) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |l| l.len());
+        let mut cells = vec![];
+        let mut height = 0;
+        for (n, line) in input.lines().enumerate() {
+            same_width(day, width, line)
+                .and_then(|_| digits(day, line))
+                .map(|row| cells.extend(row))
+                .map_err(|e| e.on_line(n + 1))?;
+            height += 1;
+        }
+        Ok(Self::from_cells(width, height, cells))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) off grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) off grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid_parse() {
+    let grid = Grid::parse_digits(9, "219\n398").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 9);
+    assert_eq!(grid[(0, 1)], 3);
+    assert_eq!(grid.to_string(), "219\n398");
+    assert_eq!(
+        Grid::parse_digits(9, "219\n3x8").err(),
+        Some(ParseError::new(9, 2, 2, "a digit"))
+    );
+
+    let grid = Grid::parse(25, ".>\nv.", ">, v or .", |c| {
+        matches!(c, '.' | '>' | 'v').then_some(c)
+    })
+    .unwrap();
+    assert_eq!(grid[(0, 1)], 'v');
+    assert_eq!(
+        Grid::parse(25, ".>\n<.", ">, v or .", |c| {
+            matches!(c, '.' | '>' | 'v').then_some(c)
+        })
+        .err(),
+        Some(ParseError::new(25, 2, 1, ">, v or ."))
+    );
+    assert_eq!(
+        Grid::parse(25, ".>\n.", "", Some).err(),
+        Some(ParseError::new(25, 2, 2, "2 columns"))
+    );
+}
+
+#[test]
+fn test_grid_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(
+        grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1), (2, 1), (1, 2)]
+    );
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(
+        grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+        [(1, 1), (2, 1), (1, 2)]
+    );
+}
+
+#[test]
+fn test_grid_outside() {
+    let mut grid = Grid::parse_digits(0, "12\n34").unwrap();
+    assert_eq!(grid.get(1, 1), Some(&4));
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.wrapping(-1, 2), &2);
+    assert_eq!(grid.wrap(5, -3), (1, 1));
+
+    grid.set_background(0);
+    assert_eq!(grid.get(-1, 0), Some(&0));
+    assert_eq!(grid.get(100, 100), Some(&0));
+
+    let padded = grid.pad(1, 0);
+    assert_eq!(padded.to_string(), "0000\n0120\n0340\n0000");
+    assert_eq!(padded.background(), Some(&0));
+    assert_eq!(
+        padded
+            .map(|&c| c > 2)
+            .render(|&b| if b { '#' } else { '.' }),
+        "....\n....\n.##.\n...."
+    );
+}
@@ -5,30 +5,26 @@ extern crate aoc_runner_derive;
 #[macro_use]
 extern crate pretty_assertions;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geom;
+pub mod grid;
+pub mod parallel;
+pub mod params;
+pub mod random;
+pub mod record;
+pub mod render;
+pub mod runner;
+pub mod solver;
+pub mod trace;
 
+// A module per year, each day in it registered with solver.rs
+pub mod y2021;
+
+// cargo-aoc only knows the one year per crate, so it sees 2021's #[aoc]s
 aoc_lib! { year = 2021 }
@@ -0,0 +1,634 @@
+use advent2021::{
+    animate::{play, write_frames},
+    answers::{answers_path, Answers, Check},
+    bench::{bench, table, write_csv, Timing},
+    examples::{self, check, discover, examples_dir},
+    fetch::{self, Fetched, Fetcher},
+    parallel,
+    params::Overrides,
+    record::{self, Record},
+    render::Format,
+    runner::{open_input, read_input, Source},
+    solver::{lookup, solutions, years, Parsed, Solution, DEFAULT_VARIANT},
+    trace,
+};
+use clap::Parser;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Instant,
+};
+
+/// Run Advent of Code solutions without cargo-aoc
+#[derive(Parser, Debug)]
+#[command(name = "advent2021")]
+struct Args {
+    /// Year the days are from
+    #[arg(short, long, default_value_t = 2021)]
+    year: u32,
+
+    /// Day to run
+    #[arg(short, long, required_unless_present_any = ["all", "examples"], conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Part to run, both when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Run every day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Read input from this file, or stdin for `-`.  Defaults to input/YEAR/dayN.txt
+    #[arg(short, long, conflicts_with_all = ["example", "all"])]
+    input: Option<String>,
+
+    /// Use the worked example from the puzzle text
+    #[arg(short, long)]
+    example: bool,
+
+    /// Check answers against those recorded in answers/YEAR.toml
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    verify: bool,
+
+    /// Time every variant of each part over this many runs
+    #[arg(long, value_name = "RUNS", conflicts_with = "verify")]
+    bench: Option<usize>,
+
+    /// Where --bench writes its timings as CSV
+    #[arg(long, default_value = "target/bench.csv")]
+    csv: PathBuf,
+
+    /// Save a picture of the day once solved, as .ppm, .pgm or .svg
+    #[arg(long, value_name = "PATH", requires = "day", conflicts_with_all = ["verify", "bench"])]
+    render: Option<PathBuf>,
+
+    /// Save every step of the day's simulation as numbered PPMs in this directory
+    #[arg(long, value_name = "DIR", requires = "day", conflicts_with_all = ["verify", "bench", "render"])]
+    frames: Option<PathBuf>,
+
+    /// Play the day's simulation in the terminal
+    #[arg(long, requires = "day", conflicts_with_all = ["verify", "bench", "render"])]
+    play: bool,
+
+    /// Frames per second for --play
+    #[arg(long, default_value_t = 10)]
+    fps: u32,
+
+    /// Print a made up input for the day, the same one for the same seed
+    #[arg(long, value_name = "SEED", requires = "day", conflicts_with_all = ["verify", "bench", "render", "frames", "play"])]
+    generate: Option<u64>,
+
+    /// Run every part at once on a pool of workers, then report on them together
+    #[arg(long, conflicts_with_all = ["bench", "render", "frames", "play", "generate"])]
+    parallel: bool,
+
+    /// How many workers --parallel uses, one per core by default
+    #[arg(long, value_name = "N", requires = "parallel")]
+    workers: Option<usize>,
+
+    /// Print a record per part and variant as jsonl or csv, rather than the answers
+    #[arg(long, value_name = "FORMAT", conflicts_with_all = ["bench", "render", "frames", "play", "generate"])]
+    format: Option<record::Format>,
+
+    /// Override one of a day's puzzle constants, like day6.part1_days=18
+    #[arg(long = "set", value_name = "DAYN.NAME=VALUE")]
+    settings: Vec<String>,
+
+    /// Read overrides for puzzle constants from a TOML file, a [dayN] table per day
+    #[arg(long, value_name = "PATH")]
+    params: Option<PathBuf>,
+
+    /// Log what the solutions for this day get up to on the way to their answers
+    #[arg(long, value_name = "DAY", conflicts_with_all = ["bench", "parallel", "format"])]
+    trace: Vec<u32>,
+
+    /// Read the input a line at a time rather than all at once, for huge inputs
+    #[arg(long, requires = "day", conflicts_with_all = ["bench", "render", "frames", "play", "generate", "parallel", "format", "trace"])]
+    stream: bool,
+
+    /// Download the day's input to input/YEAR unless it's already there, using
+    /// the session token in AOC_SESSION or .aoc-session
+    #[arg(long, conflicts_with_all = ["input", "example", "verify", "bench", "render", "frames", "play", "generate", "parallel", "format", "stream"])]
+    fetch: bool,
+
+    /// Check every example file against the answers kept beside it, and list
+    /// those with none yet
+    #[arg(long, conflicts_with_all = ["input", "example", "verify", "bench", "render", "frames", "play", "generate", "parallel", "format", "stream", "fetch"])]
+    examples: bool,
+
+    /// Include the parts --examples would skip for being slow
+    #[arg(long, requires = "examples")]
+    slow: bool,
+
+    /// Where --examples finds the example files, as a directory holding y2021
+    /// and so on.  Defaults to src, here or in the checkout this was built from
+    #[arg(long, value_name = "DIR", requires = "examples")]
+    examples_dir: Option<PathBuf>,
+
+    /// How big an input --generate makes, in lines, grid side or the like for the day
+    #[arg(long, requires = "generate")]
+    size: Option<usize>,
+}
+
+fn parsed_input(
+    solution: &Solution,
+    source: &Source,
+    overrides: &Overrides,
+) -> Result<Parsed, String> {
+    let input = read_input(solution, source).map_err(|e| format!("Day {}: {}", solution.day, e))?;
+    let mut parsed = solution.parse(&input).map_err(|e| e.to_string())?;
+    solution
+        .configure(&mut parsed, overrides.day(solution.day))
+        .map_err(|e| format!("Day {}: {}", solution.day, e))?;
+    Ok(parsed)
+}
+
+// What to say about an answer, and whether it was as expected
+fn verdict(day: u32, part: u32, answer: String, answers: Option<&Answers>) -> (String, bool) {
+    match answers.map(|a| a.check(day, part, &answer)) {
+        None => (answer, true),
+        Some(Check::Match) => (String::from("ok"), true),
+        Some(Check::Unrecorded) => (format!("{} (no recorded answer)", answer), true),
+        Some(Check::Mismatch { expected }) => (
+            format!("MISMATCH, expected {} but got {}", expected, answer),
+            false,
+        ),
+    }
+}
+
+// Runs the selected parts, returning whether every answer was as expected.
+// Verifying checks every variant, not just the default.  Traced events go to
+// stderr, leaving the answers on stdout.
+fn run(
+    solution: &Solution,
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    answers: Option<&Answers>,
+    traced: bool,
+) -> Result<bool, String> {
+    let parsed = parsed_input(solution, source, overrides)?;
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=solution.parts,
+    };
+    let mut good = true;
+    for (part, variant) in parts.flat_map(|p| solution.variants(p).map(move |v| (p, v))) {
+        if answers.is_none() && variant != DEFAULT_VARIANT {
+            continue;
+        }
+        let solve = || solution.solve_variant(&parsed, part, variant).unwrap();
+        let answer = if traced {
+            let day = solution.day;
+            let log = move |event: &trace::Event| match variant {
+                DEFAULT_VARIANT => eprintln!("day {} part {}: {}", day, part, event),
+                _ => eprintln!("day {} part {} ({}): {}", day, part, variant, event),
+            };
+            trace::traced(log, solve)
+        } else {
+            solve()
+        };
+        let (verdict, ok) = verdict(solution.day, part, answer, answers);
+        good &= ok;
+        if variant == DEFAULT_VARIANT {
+            println!("Day {} - Part {} : {}", solution.day, part, verdict);
+        } else {
+            println!(
+                "Day {} - Part {} ({}) : {}",
+                solution.day, part, variant, verdict
+            );
+        }
+    }
+    Ok(good)
+}
+
+// Each part reads the input afresh, so stdin can only stream the one part
+fn run_stream(
+    solution: &Solution,
+    source: &Source,
+    part: Option<u32>,
+    answers: Option<&Answers>,
+) -> Result<bool, String> {
+    let named = part.is_some();
+    let parts = match part {
+        Some(part) => part..=part,
+        None if *source == Source::Stdin && solution.parts > 1 => {
+            return Err(String::from(
+                "stdin can only be streamed for one part, choose it with --part",
+            ))
+        }
+        None => 1..=solution.parts,
+    };
+    let mut good = true;
+    for part in parts {
+        let mut reader =
+            open_input(solution, source).map_err(|e| format!("Day {}: {}", solution.day, e))?;
+        let verdict = match solution.stream(&mut reader, part) {
+            Some(answer) => {
+                let answer = answer.map_err(|e| format!("Day {}: {}", solution.day, e))?;
+                let (verdict, ok) = verdict(solution.day, part, answer, answers);
+                good &= ok;
+                verdict
+            }
+            // Only a failure when this part was asked for by name
+            None => {
+                good &= !named;
+                String::from("no way to solve this part a line at a time")
+            }
+        };
+        println!("Day {} - Part {} : {}", solution.day, part, verdict);
+    }
+    Ok(good)
+}
+
+fn run_fetch(selected: &[&Solution]) -> ExitCode {
+    let session = match fetch::session(
+        env::var(fetch::SESSION_VAR).ok(),
+        Path::new(fetch::SESSION_FILE),
+    ) {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("Reading {}: {}", fetch::SESSION_FILE, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let fetcher = Fetcher::new(session);
+    let mut status = ExitCode::SUCCESS;
+    for solution in selected {
+        let path = fetcher.path(solution.year, solution.day);
+        match fetcher.get(solution.year, solution.day) {
+            Ok(Fetched::Cached) => println!("Day {}: have {}", solution.day, path.display()),
+            Ok(Fetched::Downloaded) => {
+                println!("Day {}: fetched {}", solution.day, path.display())
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", solution.day, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn run_examples(root: Option<&Path>, year: u32, day: Option<u32>, slow: bool) -> ExitCode {
+    let dir = examples_dir(root, year);
+    let found = match discover(&dir) {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("{}: {}", dir.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let checked: Vec<_> = found
+        .iter()
+        .filter(|example| day.is_none_or(|day| example.day == day))
+        .filter_map(|example| Some(check(lookup(year, example.day)?, example, slow)))
+        .flatten()
+        .collect();
+    print!("{}", examples::report(&checked));
+    if examples::passed(&checked) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_bench(
+    selected: &[&Solution],
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    iterations: usize,
+    csv: &Path,
+) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    let mut timings: Vec<Timing> = vec![];
+    for solution in selected {
+        let result = read_input(solution, source)
+            .map_err(|e| format!("Day {}: {}", solution.day, e))
+            .and_then(|input| {
+                bench(solution, &input, overrides.day(solution.day), iterations)
+                    .map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(t) => timings.extend(t.into_iter().filter(|t| part.is_none_or(|p| t.part == p))),
+            Err(e) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    print!("{}", table(&timings));
+
+    let written = csv
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::File::create(csv))
+        .and_then(|mut file| write_csv(&mut file, &timings));
+    if let Err(e) = written {
+        eprintln!("Writing {}: {}", csv.display(), e);
+        return ExitCode::FAILURE;
+    }
+    status
+}
+
+fn default_workers(workers: Option<usize>) -> usize {
+    workers
+        .or_else(|| thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1)
+}
+
+fn run_jobs(
+    selected: &[&'static Solution],
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    answers: Option<&Answers>,
+    workers: usize,
+) -> Vec<parallel::Finished> {
+    let inputs = parallel::inputs(selected, source);
+    let jobs = parallel::jobs(selected, &inputs, overrides, part, answers.is_some());
+    parallel::run(&jobs, workers)
+}
+
+// Every part of the selected days at once, verifying every variant when
+// given answers the same as a run one day at a time would
+fn run_parallel(
+    selected: &[&'static Solution],
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    answers: Option<&Answers>,
+    workers: Option<usize>,
+) -> ExitCode {
+    let workers = default_workers(workers);
+    let start = Instant::now();
+    let finished = run_jobs(selected, source, overrides, part, answers, workers);
+    let elapsed = start.elapsed();
+
+    let mut good = true;
+    let report = parallel::report(&finished, |f, answer| {
+        let (verdict, ok) = verdict(f.day, f.part, answer.to_string(), answers);
+        good &= ok;
+        verdict
+    });
+    print!("{}", report);
+
+    let failed = finished.iter().filter(|f| f.answer.is_err()).count();
+    println!(
+        "{} parts on {} workers in {:.2?}, {} failed",
+        finished.len(),
+        workers,
+        elapsed,
+        failed
+    );
+    if good && failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_records(
+    selected: &[&'static Solution],
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    answers: Option<&Answers>,
+    workers: Option<usize>,
+    format: record::Format,
+) -> ExitCode {
+    let finished = run_jobs(
+        selected,
+        source,
+        overrides,
+        part,
+        answers,
+        default_workers(workers),
+    );
+    let records: Vec<Record> = finished.iter().map(|f| Record::of(f, answers)).collect();
+    if let Err(e) = record::write(&mut std::io::stdout().lock(), format, &records) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    if records
+        .iter()
+        .all(|r| matches!(r.status, record::Status::Ok | record::Status::Solved))
+    {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_render(
+    solution: &Solution,
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    path: &Path,
+) -> Result<(), String> {
+    let format = Format::of(path).ok_or_else(|| {
+        format!(
+            "Can't tell the format of {}, try .ppm, .pgm or .svg",
+            path.display()
+        )
+    })?;
+    let parsed = parsed_input(solution, source, overrides)?;
+    let picture = solution
+        .render(&parsed, part.unwrap_or(solution.parts))
+        .ok_or_else(|| format!("Day {} has nothing to draw", solution.day))?;
+    fs::write(path, format.encode(&picture))
+        .map_err(|e| format!("Writing {}: {}", path.display(), e))
+}
+
+fn run_animate(
+    solution: &Solution,
+    source: &Source,
+    overrides: &Overrides,
+    part: Option<u32>,
+    args: &Args,
+) -> Result<(), String> {
+    let parsed = parsed_input(solution, source, overrides)?;
+    let frames = solution
+        .animate(&parsed, part.unwrap_or(solution.parts))
+        .ok_or_else(|| format!("Day {} has no simulation to watch", solution.day))?;
+    if let Some(dir) = &args.frames {
+        write_frames(&frames, dir, Format::Ppm)
+            .map_err(|e| format!("Writing {}: {}", dir.display(), e))?;
+    }
+    if args.play {
+        play(&frames, &mut std::io::stdout().lock(), args.fps).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let source = match (&args.input, args.example) {
+        (Some(path), _) => Source::from_arg(path),
+        (None, true) => Source::Example,
+        (None, false) => Source::Default,
+    };
+
+    let answers = if args.verify {
+        match Answers::load(answers_path(args.year)) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let overrides = args
+        .params
+        .as_ref()
+        .map_or(Ok(Overrides::default()), Overrides::load)
+        .and_then(|mut overrides| {
+            args.settings
+                .iter()
+                .try_for_each(|arg| overrides.set_arg(arg))
+                .map(|_| overrides)
+        });
+    let overrides = match overrides {
+        Ok(overrides) => overrides,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.examples {
+        return run_examples(args.examples_dir.as_deref(), args.year, args.day, args.slow);
+    }
+
+    let mut selected: Vec<&Solution> = match args.day {
+        Some(day) => match lookup(args.year, day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("No solution for day {} of {}", day, args.year);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => solutions().filter(|s| s.year == args.year).collect(),
+    };
+    if selected.is_empty() {
+        eprintln!(
+            "No solutions for {}, only {}",
+            args.year,
+            years()
+                .map(|y| y.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(seed) = args.generate {
+        let solution = selected[0];
+        println!(
+            "{}",
+            solution.random(seed, args.size.unwrap_or(solution.random_size))
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    if args.fetch {
+        return run_fetch(&selected);
+    }
+
+    if let Some(path) = &args.render {
+        return match run_render(selected[0], &source, &overrides, args.part, path) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.frames.is_some() || args.play {
+        return match run_animate(selected[0], &source, &overrides, args.part, &args) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.stream {
+        return match run_stream(selected[0], &source, args.part, answers.as_ref()) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(iterations) = args.bench {
+        return run_bench(
+            &selected, &source, &overrides, args.part, iterations, &args.csv,
+        );
+    }
+
+    // With --all --example, skip the days we never kept an example for
+    if args.all && source == Source::Example {
+        selected.retain(|solution| solution.example.is_some());
+    }
+
+    if let Some(format) = args.format {
+        // One at a time unless asked otherwise, so the timings aren't skewed
+        let workers = if args.parallel { args.workers } else { Some(1) };
+        return run_records(
+            &selected,
+            &source,
+            &overrides,
+            args.part,
+            answers.as_ref(),
+            workers,
+            format,
+        );
+    }
+
+    if args.parallel {
+        return run_parallel(
+            &selected,
+            &source,
+            &overrides,
+            args.part,
+            answers.as_ref(),
+            args.workers,
+        );
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    for solution in selected {
+        let traced = args.trace.contains(&solution.day);
+        match run(
+            solution,
+            &source,
+            &overrides,
+            args.part,
+            answers.as_ref(),
+            traced,
+        ) {
+            Ok(true) => {}
+            Ok(false) => status = ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}", e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
@@ -0,0 +1,270 @@
+use crate::params::Overrides;
+use crate::runner::{input_hash, read_input, Source};
+use crate::solver::{Solution, DEFAULT_VARIANT};
+use std::{
+    any::Any,
+    fmt::Write as _,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+// One variant of one part of a day, for a worker to pick up.  Parsed inputs
+// can't cross threads, so each job parses its own copy of the day's input.
+#[derive(Clone)]
+pub struct Job<'a> {
+    pub solution: &'static Solution,
+    pub part: u32,
+    pub variant: &'static str,
+    pub input: Result<&'a str, String>,
+    pub params: &'a [(String, String)],
+}
+
+// How a job went.  A day that panics only takes its own jobs down with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finished {
+    pub day: u32,
+    pub part: u32,
+    pub variant: &'static str,
+    pub answer: Result<String, String>,
+    pub input_hash: Option<String>,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("panicked"))
+}
+
+// Every day's input, read up front since stdin can only be read the once
+pub fn inputs(selected: &[&'static Solution], source: &Source) -> Vec<Result<String, String>> {
+    selected
+        .iter()
+        .map(|solution| {
+            read_input(solution, source).map_err(|e| format!("Day {}: {}", solution.day, e))
+        })
+        .collect()
+}
+
+// The parts of each day to run, with every variant when `variants` is set
+pub fn jobs<'a>(
+    selected: &[&'static Solution],
+    inputs: &'a [Result<String, String>],
+    overrides: &'a Overrides,
+    part: Option<u32>,
+    variants: bool,
+) -> Vec<Job<'a>> {
+    let mut jobs = vec![];
+    for (&solution, input) in selected.iter().zip(inputs) {
+        let parts = match part {
+            Some(part) => part..=part,
+            None => 1..=solution.parts,
+        };
+        for part in parts {
+            for variant in solution.variants(part) {
+                if variants || variant == DEFAULT_VARIANT {
+                    jobs.push(Job {
+                        solution,
+                        part,
+                        variant,
+                        input: input.as_deref().map_err(String::clone),
+                        params: overrides.day(solution.day),
+                    });
+                }
+            }
+        }
+    }
+    jobs
+}
+
+fn run_job(job: &Job) -> Finished {
+    let mut finished = Finished {
+        day: job.solution.day,
+        part: job.part,
+        variant: job.variant,
+        answer: Err(String::new()),
+        input_hash: None,
+        parse: Duration::ZERO,
+        solve: Duration::ZERO,
+    };
+    let input = match &job.input {
+        Ok(input) => input,
+        Err(e) => {
+            finished.answer = Err(e.clone());
+            return finished;
+        }
+    };
+    finished.input_hash = Some(input_hash(input));
+    let answer = catch_unwind(AssertUnwindSafe(|| {
+        let start = Instant::now();
+        let parsed = job.solution.parse(input);
+        finished.parse = start.elapsed();
+        let mut parsed = parsed.map_err(|e| e.to_string())?;
+        job.solution
+            .configure(&mut parsed, job.params)
+            .map_err(|e| format!("Day {}: {}", job.solution.day, e))?;
+
+        let start = Instant::now();
+        let answer = job.solution.solve_variant(&parsed, job.part, job.variant);
+        finished.solve = start.elapsed();
+        answer.ok_or_else(|| format!("no part {} {}", job.part, job.variant))
+    }));
+    finished.answer =
+        answer.unwrap_or_else(|panic| Err(format!("panicked: {}", panic_message(panic.as_ref()))));
+    finished
+}
+
+// Runs the jobs on `workers` threads, each taking the next job going as it
+// finishes the last, and hands back how they went in the order given
+pub fn run(jobs: &[Job], workers: usize) -> Vec<Finished> {
+    let next = AtomicUsize::new(0);
+    let mut finished: Vec<(usize, Finished)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..workers.clamp(1, jobs.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        match jobs.get(index) {
+                            Some(job) => done.push((index, run_job(job))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    finished.sort_by_key(|&(index, _)| index);
+    finished.into_iter().map(|(_, f)| f).collect()
+}
+
+// One line per job, the answer last as some span several lines.  `verdict`
+// gets the final say on what's shown for an answer, to allow for checking it.
+pub fn report(finished: &[Finished], mut verdict: impl FnMut(&Finished, &str) -> String) -> String {
+    let mut out = format!(
+        "{:>3} {:>4} {:<12} {:>12} {:>12}  {}\n",
+        "day", "part", "variant", "parse", "solve", "answer"
+    );
+    for f in finished {
+        let answer = match &f.answer {
+            Ok(answer) => verdict(f, answer),
+            Err(e) => format!("FAILED, {}", e),
+        };
+        writeln!(
+            out,
+            "{:>3} {:>4} {:<12} {:>12} {:>12}  {}",
+            f.day,
+            f.part,
+            f.variant,
+            format!("{:.2?}", f.parse),
+            format!("{:.2?}", f.solve),
+            answer
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[test]
+fn test_run() {
+    use crate::solver::lookup;
+
+    let selected = [lookup(2021, 1).unwrap(), lookup(2021, 22).unwrap()];
+    let inputs = vec![
+        Err(String::from("Day 1: no example bundled for day 1")),
+        Ok(String::from(selected[1].example.unwrap())),
+    ];
+    let overrides = Overrides::default();
+    let jobs = jobs(&selected, &inputs, &overrides, None, true);
+    assert_eq!(
+        jobs.iter()
+            .map(|j| (j.solution.day, j.part, j.variant))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, 1, "default"),
+            (1, 2, "default"),
+            (22, 1, "default"),
+            (22, 1, "slicing"),
+            (22, 2, "default")
+        ]
+    );
+
+    let finished = run(&jobs, 3);
+    assert_eq!(finished.len(), 5);
+    assert!(finished[..2].iter().all(|f| f.answer.is_err()));
+    assert_eq!(finished[2].answer, Ok(String::from("590784")));
+    assert_eq!(finished[3].answer, Ok(String::from("590784")));
+
+    let report = report(&finished, |_, answer| answer.to_string());
+    assert_eq!(report.lines().count(), 6);
+    assert!(report
+        .lines()
+        .nth(1)
+        .unwrap()
+        .ends_with("FAILED, Day 1: no example bundled for day 1"));
+}
+
+// A day whose part 2 always panics
+#[cfg(test)]
+struct Panics;
+
+#[cfg(test)]
+impl crate::solver::Solver for Panics {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, crate::error::ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        input.len().to_string()
+    }
+
+    fn part2(_input: &Self::Input) -> String {
+        panic!("part 2 gave up")
+    }
+
+    fn random(_rng: &mut crate::random::Random, _size: usize) -> String {
+        String::new()
+    }
+}
+
+#[test]
+fn test_run_survives_failures() {
+    use crate::solver::lookup;
+
+    // Day 2 has nothing to configure
+    let panics: &'static Solution = Box::leak(Box::new(Solution::of::<Panics>(2021, 99)));
+    let selected = [panics, lookup(2021, 2).unwrap()];
+    let inputs = vec![
+        Ok(String::from("input")),
+        Ok(String::from(selected[1].example.unwrap())),
+    ];
+    let mut overrides = Overrides::default();
+    overrides.set(2, "depth", "1");
+    let finished = run(&jobs(&selected, &inputs, &overrides, None, false), 2);
+    assert_eq!(
+        finished
+            .iter()
+            .map(|f| f.answer.is_ok())
+            .collect::<Vec<_>>(),
+        vec![true, false, false, false]
+    );
+    assert_eq!(
+        finished[1].answer,
+        Err(String::from("panicked: part 2 gave up"))
+    );
+    assert_eq!(
+        finished[2].answer,
+        Err(String::from("Day 2: no parameter called depth"))
+    );
+}
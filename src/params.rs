@@ -0,0 +1,208 @@
+use std::{collections::BTreeMap, error::Error, fmt, fs, io, path::Path, str::FromStr};
+
+// The constants a day's puzzle is posed with, like how many days day 6's fish
+// breed for.  Each day with any has a struct of them defaulting to the puzzle
+// as set, and overrides are applied by name.
+pub trait Params: Default {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+}
+
+// A day's parsed input, along with the constants to solve it with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Configured<I, P> {
+    pub input: I,
+    pub params: P,
+}
+
+impl<I, P: Params> Configured<I, P> {
+    pub fn new(input: I) -> Self {
+        Configured {
+            input,
+            params: P::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Unknown(String),
+    Invalid { name: String, value: String },
+    Layout(String),
+    Io(io::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "no parameter called {}", name),
+            ParamError::Invalid { name, value } => write!(f, "{} can't be {}", name, value),
+            ParamError::Layout(e) => write!(f, "parameters: {}", e),
+            ParamError::Io(e) => write!(f, "reading parameters: {}", e),
+            ParamError::Toml(e) => write!(f, "parsing parameters: {}", e),
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+impl From<io::Error> for ParamError {
+    fn from(e: io::Error) -> Self {
+        ParamError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ParamError {
+    fn from(e: toml::de::Error) -> Self {
+        ParamError::Toml(e)
+    }
+}
+
+pub fn invalid(name: &str, value: &str) -> ParamError {
+    ParamError::Invalid {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+// For Params::set, reading a value of whatever type the field is
+pub fn value<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value.parse().map_err(|_| invalid(name, value))
+}
+
+// The same, for the constants that only make sense from `min` up
+pub fn at_least<T: FromStr + PartialOrd>(name: &str, v: &str, min: T) -> Result<T, ParamError> {
+    let parsed = value(name, v)?;
+    if parsed < min {
+        return Err(invalid(name, v));
+    }
+    Ok(parsed)
+}
+
+// And for those that also get too slow, or overflow, past `max`
+pub fn between<T: FromStr + PartialOrd>(
+    name: &str,
+    v: &str,
+    min: T,
+    max: T,
+) -> Result<T, ParamError> {
+    let parsed = at_least(name, v, min)?;
+    if parsed > max {
+        return Err(invalid(name, v));
+    }
+    Ok(parsed)
+}
+
+// A value from a TOML table of settings, as if given on the command line
+pub fn setting(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// Parameters to override, by day.  Read from TOML with a table per day like
+// the answers file,
+//
+//     [day6]
+//     part1_days = 18
+//
+// or given one at a time as day6.part1_days=18.  Later settings win.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Overrides(BTreeMap<u32, Vec<(String, String)>>);
+
+fn day_number(key: &str) -> Result<u32, ParamError> {
+    key.strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| ParamError::Layout(format!("expected a day like day6, not {}", key)))
+}
+
+impl Overrides {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ParamError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParamError> {
+        let table: toml::Table = text.parse()?;
+        let mut overrides = Overrides::default();
+        for (key, params) in table {
+            let day = day_number(&key)?;
+            let params = params
+                .as_table()
+                .ok_or_else(|| ParamError::Layout(format!("{} should be a table", key)))?;
+            for (name, value) in params {
+                overrides.set(day, name, &setting(value));
+            }
+        }
+        Ok(overrides)
+    }
+
+    pub fn set(&mut self, day: u32, name: &str, value: &str) {
+        self.0
+            .entry(day)
+            .or_default()
+            .push((name.to_string(), value.to_string()));
+    }
+
+    // day6.part1_days=18, as given on the command line
+    pub fn set_arg(&mut self, arg: &str) -> Result<(), ParamError> {
+        let layout = || ParamError::Layout(format!("expected dayN.name=value, not {}", arg));
+        let (key, value) = arg.split_once('=').ok_or_else(layout)?;
+        let (day, name) = key.split_once('.').ok_or_else(layout)?;
+        self.set(day_number(day)?, name, value);
+        Ok(())
+    }
+
+    pub fn day(&self, day: u32) -> &[(String, String)] {
+        self.0.get(&day).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[test]
+fn test_overrides() {
+    let mut overrides =
+        Overrides::parse("[day6]\npart1_days = 18\n\n[day11]\nsteps = \"10\"").unwrap();
+    overrides.set_arg("day6.part1_days=20").unwrap();
+    assert_eq!(
+        overrides.day(6),
+        [
+            (String::from("part1_days"), String::from("18")),
+            (String::from("part1_days"), String::from("20"))
+        ]
+    );
+    assert_eq!(
+        overrides.day(11),
+        [(String::from("steps"), String::from("10"))]
+    );
+    assert!(overrides.day(1).is_empty());
+
+    assert!(matches!(
+        overrides.set_arg("day6.part1_days"),
+        Err(ParamError::Layout(_))
+    ));
+    assert!(matches!(
+        overrides.set_arg("six.part1_days=1"),
+        Err(ParamError::Layout(_))
+    ));
+    assert!(matches!(
+        Overrides::parse("day6 = 1"),
+        Err(ParamError::Layout(_))
+    ));
+    assert_eq!(value::<u32>("steps", "12").unwrap(), 12);
+    assert_eq!(
+        value::<u32>("steps", "-1").unwrap_err().to_string(),
+        "steps can't be -1"
+    );
+    assert_eq!(at_least::<u32>("tiles", "1", 1).unwrap(), 1);
+    assert_eq!(
+        at_least::<u32>("tiles", "0", 1).unwrap_err().to_string(),
+        "tiles can't be 0"
+    );
+    assert_eq!(between::<u32>("tiles", "20", 1, 20).unwrap(), 20);
+    assert_eq!(
+        between::<u32>("tiles", "21", 1, 20)
+            .unwrap_err()
+            .to_string(),
+        "tiles can't be 21"
+    );
+}
@@ -0,0 +1,50 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Made up puzzle inputs, for trying solutions on more than the one input we
+// were given.  ChaCha8 keeps to the same stream on every platform and release,
+// so a seed always makes the same input.
+pub type Random = ChaCha8Rng;
+
+pub fn seeded(seed: u64) -> Random {
+    Random::seed_from_u64(seed)
+}
+
+// `count` lines, each made by `line`
+pub fn lines(
+    rng: &mut Random,
+    count: usize,
+    mut line: impl FnMut(&mut Random) -> String,
+) -> String {
+    (0..count).map(|_| line(rng)).collect::<Vec<_>>().join("\n")
+}
+
+// A grid a character per cell, a line per row
+pub fn grid(
+    rng: &mut Random,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut Random) -> char,
+) -> String {
+    (0..height)
+        .map(|_| (0..width).map(|_| cell(rng)).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// A digit from `digits`, for heightmaps and the like
+pub fn digit(rng: &mut Random, digits: std::ops::RangeInclusive<u32>) -> char {
+    char::from_digit(rng.gen_range(digits), 10).unwrap()
+}
+
+#[test]
+fn test_seeded() {
+    let draw = |seed| {
+        let mut rng = seeded(seed);
+        grid(&mut rng, 4, 3, |rng| digit(rng, 0..=9))
+    };
+    assert_eq!(draw(1), draw(1));
+    assert_ne!(draw(1), draw(2));
+    assert_eq!(draw(1).lines().count(), 3);
+    assert!(draw(1).lines().all(|l| l.len() == 4));
+}
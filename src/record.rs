@@ -0,0 +1,208 @@
+use crate::answers::{Answers, Check};
+use crate::parallel::Finished;
+use std::{
+    fmt,
+    io::{self, Write},
+    str::FromStr,
+};
+
+// Machine readable results, one per day, part and variant, for loading into
+// dashboards or diffing between commits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    JsonLines,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" | "json" => Ok(Format::JsonLines),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {}, try jsonl or csv", s)),
+        }
+    }
+}
+
+// Solved is an answer we had nothing to check against, either because we
+// weren't verifying or there's no recorded answer for the part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Solved,
+    Mismatch,
+    Failed,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Ok => "ok",
+            Status::Solved => "solved",
+            Status::Mismatch => "mismatch",
+            Status::Failed => "failed",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub variant: &'static str,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    pub input_hash: Option<String>,
+}
+
+impl Record {
+    pub fn of(finished: &Finished, answers: Option<&Answers>) -> Self {
+        let (status, answer, error) = match &finished.answer {
+            Ok(answer) => {
+                let status = match answers.map(|a| a.check(finished.day, finished.part, answer)) {
+                    Some(Check::Match) => Status::Ok,
+                    Some(Check::Mismatch { .. }) => Status::Mismatch,
+                    Some(Check::Unrecorded) | None => Status::Solved,
+                };
+                (status, Some(answer.clone()), None)
+            }
+            Err(e) => (Status::Failed, None, Some(e.clone())),
+        };
+        Record {
+            day: finished.day,
+            part: finished.part,
+            variant: finished.variant,
+            status,
+            answer,
+            error,
+            parse_ns: finished.parse.as_nanos(),
+            solve_ns: finished.solve.as_nanos(),
+            input_hash: finished.input_hash.clone(),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_option(s: &Option<String>) -> String {
+    s.as_deref().map_or(String::from("null"), json_string)
+}
+
+// Quoted only when it has to be, so day 13's picture is the one quoted field
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn write(out: &mut impl Write, format: Format, records: &[Record]) -> io::Result<()> {
+    if format == Format::Csv {
+        writeln!(
+            out,
+            "day,part,variant,status,answer,error,parse_ns,solve_ns,input_hash"
+        )?;
+    }
+    for r in records {
+        match format {
+            Format::JsonLines => writeln!(
+                out,
+                "{{\"day\":{},\"part\":{},\"variant\":{},\"status\":\"{}\",\"answer\":{},\"error\":{},\"parse_ns\":{},\"solve_ns\":{},\"input_hash\":{}}}",
+                r.day,
+                r.part,
+                json_string(r.variant),
+                r.status,
+                json_option(&r.answer),
+                json_option(&r.error),
+                r.parse_ns,
+                r.solve_ns,
+                json_option(&r.input_hash)
+            )?,
+            Format::Csv => writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{}",
+                r.day,
+                r.part,
+                csv_field(r.variant),
+                r.status,
+                csv_field(r.answer.as_deref().unwrap_or_default()),
+                csv_field(r.error.as_deref().unwrap_or_default()),
+                r.parse_ns,
+                r.solve_ns,
+                r.input_hash.as_deref().unwrap_or_default()
+            )?,
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write() {
+    use std::time::Duration;
+
+    let finished = |part, answer: Result<&str, &str>| Finished {
+        day: 13,
+        part,
+        variant: "default",
+        answer: answer.map(String::from).map_err(String::from),
+        input_hash: answer.ok().map(|_| String::from("cbf29ce484222325")),
+        parse: Duration::from_nanos(1500),
+        solve: Duration::from_micros(2),
+    };
+    let answers = Answers::parse("[day13]\npart1 = \"17\"").unwrap();
+    let records = [
+        Record::of(&finished(1, Ok("17")), Some(&answers)),
+        Record::of(&finished(2, Ok("#\"#\n##")), Some(&answers)),
+        Record::of(&finished(2, Err("Day 13: no input")), None),
+    ];
+    assert_eq!(
+        records.iter().map(|r| r.status).collect::<Vec<_>>(),
+        vec![Status::Ok, Status::Solved, Status::Failed]
+    );
+
+    let mut jsonl = vec![];
+    write(&mut jsonl, Format::JsonLines, &records).unwrap();
+    assert_eq!(
+        String::from_utf8(jsonl).unwrap(),
+        r##"{"day":13,"part":1,"variant":"default","status":"ok","answer":"17","error":null,"parse_ns":1500,"solve_ns":2000,"input_hash":"cbf29ce484222325"}
+{"day":13,"part":2,"variant":"default","status":"solved","answer":"#\"#\n##","error":null,"parse_ns":1500,"solve_ns":2000,"input_hash":"cbf29ce484222325"}
+{"day":13,"part":2,"variant":"default","status":"failed","answer":null,"error":"Day 13: no input","parse_ns":1500,"solve_ns":2000,"input_hash":null}
+"##
+    );
+
+    let mut csv = vec![];
+    write(&mut csv, Format::Csv, &records).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        r##"day,part,variant,status,answer,error,parse_ns,solve_ns,input_hash
+13,1,default,ok,17,,1500,2000,cbf29ce484222325
+13,2,default,solved,"#""#
+##",,1500,2000,cbf29ce484222325
+13,2,default,failed,,Day 13: no input,1500,2000,
+"##
+    );
+
+    assert_eq!("csv".parse(), Ok(Format::Csv));
+    assert!("xml".parse::<Format>().is_err());
+}
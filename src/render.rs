@@ -0,0 +1,186 @@
+use crate::geom::{Bounds, Vec2};
+use crate::grid::Grid;
+use itertools::Itertools;
+use std::{fmt::Write as _, path::Path};
+
+// Pictures of puzzle states, a colour per cell, and the files to save them as.
+// PPM and PGM are the simplest bitmaps there are; SVG scales for review.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+pub type Picture = Grid<Rgb>;
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 30, 30);
+
+    pub fn grey(level: u8) -> Self {
+        Rgb(level, level, level)
+    }
+
+    // Perceived brightness, for greyscale output
+    pub fn luma(&self) -> u8 {
+        ((299 * self.0 as u32 + 587 * self.1 as u32 + 114 * self.2 as u32) / 1000) as u8
+    }
+}
+
+// From black for 0 up to white for `max`
+pub fn shade(value: u64, max: u64) -> Rgb {
+    Rgb::grey((value.min(max) * 255 / max.max(1)) as u8)
+}
+
+// Black through red and yellow to white, for counting things up
+pub fn heat(value: u64, max: u64) -> Rgb {
+    let t = (value.min(max) * 765 / max.max(1)) as u32;
+    let channel = |from: u32| t.saturating_sub(from).min(255) as u8;
+    Rgb(channel(0), channel(255), channel(510))
+}
+
+// Colours easy to tell apart, for labelling regions
+pub fn palette(i: usize) -> Rgb {
+    const COLOURS: [Rgb; 10] = [
+        Rgb(230, 25, 75),
+        Rgb(60, 180, 75),
+        Rgb(255, 225, 25),
+        Rgb(0, 130, 200),
+        Rgb(245, 130, 48),
+        Rgb(145, 30, 180),
+        Rgb(70, 240, 240),
+        Rgb(240, 50, 230),
+        Rgb(210, 245, 60),
+        Rgb(250, 190, 212),
+    ];
+    COLOURS[i % COLOURS.len()]
+}
+
+// Draws coloured points on a background, just big enough to hold them
+pub fn plot(points: impl IntoIterator<Item = (Vec2, Rgb)>, background: Rgb) -> Picture {
+    let points: Vec<_> = points.into_iter().collect();
+    let bounds = match Bounds::of(points.iter().map(|&(p, _)| p)) {
+        Some(bounds) => bounds,
+        None => return Grid::new(0, 0, background),
+    };
+    let size = bounds.size();
+    let mut picture = Grid::new(size.x as usize, size.y as usize, background);
+    for (p, colour) in points {
+        let at = p - bounds.min;
+        picture[(at.x as usize, at.y as usize)] = colour;
+    }
+    picture
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Pgm,
+    Svg,
+}
+
+impl Format {
+    // Going by the file extension
+    pub fn of(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(Format::Ppm),
+            "pgm" => Some(Format::Pgm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn encode(&self, picture: &Picture) -> Vec<u8> {
+        match self {
+            Format::Ppm => ppm(picture),
+            Format::Pgm => pgm(picture),
+            Format::Svg => svg(picture, 8).into_bytes(),
+        }
+    }
+}
+
+pub fn ppm(picture: &Picture) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", picture.width(), picture.height()).into_bytes();
+    for &Rgb(r, g, b) in picture.iter() {
+        out.extend([r, g, b]);
+    }
+    out
+}
+
+pub fn pgm(picture: &Picture) -> Vec<u8> {
+    let mut out = format!("P5\n{} {}\n255\n", picture.width(), picture.height()).into_bytes();
+    out.extend(picture.iter().map(Rgb::luma));
+    out
+}
+
+// A square of `scale` units per cell, runs of a colour along a row joined up
+// to keep the file small
+pub fn svg(picture: &Picture, scale: usize) -> String {
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" shape-rendering=\"crispEdges\">\n",
+        w = picture.width() * scale,
+        h = picture.height() * scale
+    );
+    for (y, row) in picture.rows().enumerate() {
+        let mut x = 0;
+        for (run, &Rgb(r, g, b)) in row.iter().dedup_with_count() {
+            writeln!(
+                out,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                x * scale,
+                y * scale,
+                run * scale,
+                scale,
+                r,
+                g,
+                b
+            )
+            .unwrap();
+            x += run;
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+#[test]
+fn test_colours() {
+    assert_eq!(shade(0, 9), Rgb::BLACK);
+    assert_eq!(shade(9, 9), Rgb::WHITE);
+    assert_eq!(heat(0, 3), Rgb::BLACK);
+    assert_eq!(heat(1, 3), Rgb(255, 0, 0));
+    assert_eq!(heat(2, 3), Rgb(255, 255, 0));
+    assert_eq!(heat(4, 3), Rgb::WHITE);
+    assert_eq!(Rgb::RED.luma(), 86);
+    assert_ne!(palette(0), palette(1));
+}
+
+#[test]
+fn test_plot() {
+    let picture = plot(
+        [(Vec2::new(3, 1), Rgb::RED), (Vec2::new(1, 2), Rgb::WHITE)],
+        Rgb::BLACK,
+    );
+    assert_eq!((picture.width(), picture.height()), (3, 2));
+    assert_eq!(picture[(2, 0)], Rgb::RED);
+    assert_eq!(picture[(0, 1)], Rgb::WHITE);
+    assert!(plot([], Rgb::BLACK).is_empty());
+}
+
+#[test]
+fn test_encode() {
+    let picture = Grid::from_cells(2, 1, vec![Rgb::RED, Rgb::WHITE]);
+    assert_eq!(ppm(&picture), b"P6\n2 1\n255\n\xdc\x1e\x1e\xff\xff\xff");
+    assert_eq!(pgm(&picture), b"P5\n2 1\n255\n\x56\xff");
+
+    let picture = Grid::from_cells(3, 1, vec![Rgb::RED, Rgb::RED, Rgb::BLACK]);
+    assert_eq!(
+        svg(&picture, 2).lines().skip(1).collect::<Vec<_>>(),
+        [
+            "<rect x=\"0\" y=\"0\" width=\"4\" height=\"2\" fill=\"#dc1e1e\"/>",
+            "<rect x=\"4\" y=\"0\" width=\"2\" height=\"2\" fill=\"#000000\"/>",
+            "</svg>"
+        ]
+    );
+
+    assert_eq!(Format::of(Path::new("basins.svg")), Some(Format::Svg));
+    assert_eq!(Format::of(Path::new("basins.png")), None);
+}
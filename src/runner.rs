@@ -0,0 +1,113 @@
+use crate::solver::Solution;
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+// Where a run gets its puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // input/<year>/day<N>.txt, the layout cargo-aoc uses
+    Default,
+    Path(PathBuf),
+    Stdin,
+    Example,
+}
+
+impl Source {
+    // `-` is stdin, as is traditional
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+// <root>/<year>/day<N>.txt, the layout cargo-aoc uses under input/
+pub fn input_path(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{}.txt", day))
+}
+
+pub fn default_input_path(year: u32, day: u32) -> PathBuf {
+    input_path(Path::new("input"), year, day)
+}
+
+// FNV-1a over the input, as std's hashers may change between Rust releases
+// and these hashes get compared across builds
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn read_input(solution: &Solution, source: &Source) -> io::Result<String> {
+    match source {
+        Source::Default => fs::read_to_string(default_input_path(solution.year, solution.day)),
+        Source::Path(path) => fs::read_to_string(path),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Source::Example => solution.example.map(String::from).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no example bundled for day {}", solution.day),
+            )
+        }),
+    }
+}
+
+// The same input as read_input, for reading a line at a time rather than all
+// at once
+pub fn open_input(solution: &Solution, source: &Source) -> io::Result<Box<dyn BufRead>> {
+    match source {
+        Source::Default => Ok(Box::new(BufReader::new(fs::File::open(
+            default_input_path(solution.year, solution.day),
+        )?))),
+        Source::Path(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        Source::Example => read_input(solution, source)
+            .map(|example| Box::new(io::Cursor::new(example)) as Box<dyn BufRead>),
+    }
+}
+
+#[test]
+fn test_read_input() {
+    use crate::solver::lookup;
+
+    assert_eq!(Source::from_arg("-"), Source::Stdin);
+    assert_eq!(
+        Source::from_arg("day1.txt"),
+        Source::Path(PathBuf::from("day1.txt"))
+    );
+    assert_eq!(
+        default_input_path(2021, 7),
+        Path::new("input/2021/day7.txt")
+    );
+
+    let day2 = lookup(2021, 2).unwrap();
+    let example = read_input(day2, &Source::Example).unwrap();
+    assert_eq!(
+        day2.solve(&day2.parse(&example).unwrap(), 1),
+        Some(String::from("150"))
+    );
+    assert!(read_input(lookup(2021, 1).unwrap(), &Source::Example).is_err());
+
+    let mut reader = open_input(day2, &Source::Example).unwrap();
+    assert_eq!(
+        day2.stream(&mut reader, 1).and_then(Result::ok),
+        Some(String::from("150"))
+    );
+    assert!(open_input(lookup(2021, 1).unwrap(), &Source::Example).is_err());
+}
+
+#[test]
+fn test_input_hash() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    assert_ne!(input_hash("199\n200"), input_hash("200\n199"));
+}
@@ -0,0 +1,377 @@
+use crate::error::{ParseError, StreamError};
+use crate::params::ParamError;
+use crate::random::{seeded, Random};
+use crate::render::Picture;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use std::{any::Any, collections::BTreeMap, io::BufRead};
+
+// A puzzle solution usable without cargo-aoc.  The parsed input is shared
+// between both parts, the same way an aoc_generator feeds its #[aoc] runners.
+pub trait Solver {
+    type Input;
+    const PARTS: u32 = 2;
+    // The worked example from the puzzle text, where we kept one
+    const EXAMPLE: Option<&'static str> = None;
+    // How big a random input is unless asked otherwise, about the real size
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> String;
+    fn part2(input: &Self::Input) -> String;
+
+    // A valid input of about `size`, in whatever the day counts: lines, the
+    // side of a grid, and so on
+    fn random(rng: &mut Random, size: usize) -> String;
+
+    // Solve `part` reading the input a line at a time, for the days that can
+    // do without holding it all.  Days that can't leave the reader untouched.
+    fn stream(_reader: &mut dyn BufRead, _part: u32) -> Option<Result<String, StreamError>> {
+        None
+    }
+
+    // Override one of the puzzle's constants, for the days whose input is
+    // configured with some Params
+    fn configure(_input: &mut Self::Input, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(name.to_string()))
+    }
+
+    // Other ways we solved a part, like the extra #[aoc(dayN, partM, name)]s
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+
+    // A picture of the puzzle as it stands once `part` is solved
+    fn render(_input: &Self::Input, _part: u32) -> Option<Picture> {
+        None
+    }
+
+    // Pictures of every step of a simulation, on the way to solving `part`
+    fn animate(_input: &Self::Input, _part: u32) -> Option<Vec<Picture>> {
+        None
+    }
+}
+
+// part1 and part2 of a Solver go by this name among the variants
+pub const DEFAULT_VARIANT: &str = "default";
+
+pub struct Variant<I> {
+    pub part: u32,
+    pub name: &'static str,
+    pub run: fn(&I) -> String,
+}
+
+struct ErasedVariant {
+    part: u32,
+    name: &'static str,
+    run: Box<dyn Fn(&Parsed) -> String + Send + Sync>,
+}
+
+// The result of Solution::parse, to be handed back to the same Solution
+pub struct Parsed(Box<dyn Any>);
+
+// A type-erased Solver, so every day can live in the one registry
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    pub parts: u32,
+    pub example: Option<&'static str>,
+    pub random_size: usize,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part1: fn(&Parsed) -> String,
+    part2: fn(&Parsed) -> String,
+    variants: Vec<ErasedVariant>,
+    render: fn(&Parsed, u32) -> Option<Picture>,
+    animate: fn(&Parsed, u32) -> Option<Vec<Picture>>,
+    random: fn(&mut Random, usize) -> String,
+    stream: fn(&mut dyn BufRead, u32) -> Option<Result<String, StreamError>>,
+    configure: fn(&mut Parsed, &str, &str) -> Result<(), ParamError>,
+}
+
+fn erased_parse<S: Solver>(input: &str) -> Result<Parsed, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Parsed(Box::new(S::parse(input)?)))
+}
+
+fn unerased<S: Solver>(input: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .0
+        .downcast_ref()
+        .expect("input parsed by another solution")
+}
+
+fn erased_configure<S: Solver>(
+    input: &mut Parsed,
+    name: &str,
+    value: &str,
+) -> Result<(), ParamError>
+where
+    S::Input: 'static,
+{
+    let input = input
+        .0
+        .downcast_mut()
+        .expect("input parsed by another solution");
+    S::configure(input, name, value)
+}
+
+fn erased_part1<S: Solver>(input: &Parsed) -> String
+where
+    S::Input: 'static,
+{
+    S::part1(unerased::<S>(input))
+}
+
+fn erased_part2<S: Solver>(input: &Parsed) -> String
+where
+    S::Input: 'static,
+{
+    S::part2(unerased::<S>(input))
+}
+
+fn erased_render<S: Solver>(input: &Parsed, part: u32) -> Option<Picture>
+where
+    S::Input: 'static,
+{
+    S::render(unerased::<S>(input), part)
+}
+
+fn erased_animate<S: Solver>(input: &Parsed, part: u32) -> Option<Vec<Picture>>
+where
+    S::Input: 'static,
+{
+    S::animate(unerased::<S>(input), part)
+}
+
+impl Solution {
+    pub(crate) fn of<S: Solver>(year: u32, day: u32) -> Self
+    where
+        S::Input: 'static,
+    {
+        Solution {
+            year,
+            day,
+            parts: S::PARTS,
+            example: S::EXAMPLE,
+            random_size: S::RANDOM_SIZE,
+            parse: erased_parse::<S>,
+            part1: erased_part1::<S>,
+            part2: erased_part2::<S>,
+            variants: S::variants()
+                .into_iter()
+                .map(|v| ErasedVariant {
+                    part: v.part,
+                    name: v.name,
+                    run: Box::new(move |input| (v.run)(unerased::<S>(input))),
+                })
+                .collect(),
+            render: erased_render::<S>,
+            animate: erased_animate::<S>,
+            random: S::random,
+            stream: S::stream,
+            configure: erased_configure::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        // cargo-aoc hands the generators the input without its trailing newlines
+        (self.parse)(input.trim_end_matches('\n'))
+    }
+
+    // Applies a day's parameter overrides in order, see params.rs
+    pub fn configure(
+        &self,
+        input: &mut Parsed,
+        settings: &[(String, String)],
+    ) -> Result<(), ParamError> {
+        settings
+            .iter()
+            .try_for_each(|(name, value)| (self.configure)(input, name, value))
+    }
+
+    pub fn solve(&self, input: &Parsed, part: u32) -> Option<String> {
+        match part {
+            1 => Some((self.part1)(input)),
+            2 if self.parts >= 2 => Some((self.part2)(input)),
+            _ => None,
+        }
+    }
+
+    // Names of every way we have of solving `part`, the default first
+    pub fn variants(&self, part: u32) -> impl Iterator<Item = &'static str> + '_ {
+        let default = (1..=self.parts).contains(&part).then_some(DEFAULT_VARIANT);
+        default.into_iter().chain(
+            self.variants
+                .iter()
+                .filter(move |v| v.part == part)
+                .map(|v| v.name),
+        )
+    }
+
+    pub fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        part: u32,
+    ) -> Option<Result<String, StreamError>> {
+        (self.stream)(reader, part)
+    }
+
+    pub fn render(&self, input: &Parsed, part: u32) -> Option<Picture> {
+        (self.render)(input, part)
+    }
+
+    pub fn animate(&self, input: &Parsed, part: u32) -> Option<Vec<Picture>> {
+        (self.animate)(input, part)
+    }
+
+    // The same seed and size always make the same input
+    pub fn random(&self, seed: u64, size: usize) -> String {
+        (self.random)(&mut seeded(seed), size)
+    }
+
+    pub fn solve_variant(&self, input: &Parsed, part: u32, name: &str) -> Option<String> {
+        if name == DEFAULT_VARIANT {
+            return self.solve(input, part);
+        }
+        self.variants
+            .iter()
+            .find(|v| v.part == part && v.name == name)
+            .map(|v| (v.run)(input))
+    }
+}
+
+lazy_static! {
+    // Each year's days, from that year's module
+    static ref REGISTRY: BTreeMap<(u32, u32), Solution> = [crate::y2021::solutions()]
+        .into_iter()
+        .flatten()
+        .map(|s| ((s.year, s.day), s))
+        .collect();
+}
+
+pub fn lookup(year: u32, day: u32) -> Option<&'static Solution> {
+    REGISTRY.get(&(year, day))
+}
+
+pub fn solutions() -> impl Iterator<Item = &'static Solution> {
+    REGISTRY.values()
+}
+
+pub fn years() -> impl Iterator<Item = u32> {
+    REGISTRY.keys().map(|&(year, _)| year).dedup()
+}
+
+#[test]
+fn test_lookup() {
+    assert_eq!(solutions().count(), 25);
+    assert_eq!(years().collect::<Vec<_>>(), vec![2021]);
+    assert!(lookup(2021, 0).is_none());
+    assert!(lookup(2020, 1).is_none());
+
+    let day1 = lookup(2021, 1).unwrap();
+    let input = day1
+        .parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")
+        .unwrap();
+    assert_eq!(day1.solve(&input, 1), Some(String::from("7")));
+    assert_eq!(day1.solve(&input, 2), Some(String::from("5")));
+    assert_eq!(day1.solve(&input, 3), None);
+
+    let day25 = lookup(2021, 25).unwrap();
+    assert_eq!(day25.parts, 1);
+    assert_eq!(day25.solve(&day25.parse("..>\nv..\n").unwrap(), 2), None);
+
+    let day22 = lookup(2021, 22).unwrap();
+    assert_eq!(
+        day22.variants(1).collect::<Vec<_>>(),
+        vec![DEFAULT_VARIANT, "slicing"]
+    );
+    assert_eq!(day22.variants(2).collect::<Vec<_>>(), vec![DEFAULT_VARIANT]);
+    assert_eq!(day25.variants(2).count(), 0);
+    let input = day22.parse(day22.example.unwrap()).unwrap();
+    assert_eq!(
+        day22.solve_variant(&input, 1, "slicing"),
+        day22.solve(&input, 1)
+    );
+    assert_eq!(day22.solve_variant(&input, 2, "slicing"), None);
+
+    assert_eq!(
+        day1.parse("199\n200\n2O8\n").err(),
+        Some(ParseError::new(1, 3, 1, "a number"))
+    );
+}
+
+#[test]
+fn test_configure() {
+    let setting = |name: &str, value: &str| (name.to_string(), value.to_string());
+
+    let day6 = lookup(2021, 6).unwrap();
+    let mut input = day6.parse("3,4,3,1,2").unwrap();
+    day6.configure(&mut input, &[setting("part1_days", "18")])
+        .unwrap();
+    assert_eq!(day6.solve(&input, 1), Some(String::from("26")));
+    assert_eq!(day6.solve(&input, 2), Some(String::from("26984457539")));
+    assert!(day6
+        .configure(&mut input, &[setting("part2_days", "forever")])
+        .is_err());
+
+    let day1 = lookup(2021, 1).unwrap();
+    let mut input = day1.parse("199\n200").unwrap();
+    assert!(day1.configure(&mut input, &[]).is_ok());
+    assert!(day1
+        .configure(&mut input, &[setting("window", "3")])
+        .is_err());
+}
+
+#[test]
+fn test_stream() {
+    for solution in solutions() {
+        let Some(example) = solution.example else {
+            continue;
+        };
+        let parsed = solution.parse(example).unwrap();
+        for part in 1..=solution.parts {
+            if let Some(answer) = solution.stream(&mut example.as_bytes(), part) {
+                assert_eq!(answer.ok(), solution.solve(&parsed, part));
+            }
+        }
+    }
+
+    let day1 = lookup(2021, 1).unwrap();
+    let streamed = |part| {
+        day1.stream(
+            &mut "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n".as_bytes(),
+            part,
+        )
+        .map(|answer| answer.unwrap())
+    };
+    assert_eq!(streamed(1), Some(String::from("7")));
+    assert_eq!(streamed(2), Some(String::from("5")));
+    assert_eq!(
+        day1.stream(&mut "199\n2O0".as_bytes(), 1)
+            .unwrap()
+            .unwrap_err()
+            .to_string(),
+        "day 1 input, line 2, column 1: expected a number"
+    );
+    assert!(lookup(2021, 4)
+        .unwrap()
+        .stream(&mut "".as_bytes(), 1)
+        .is_none());
+}
+
+#[test]
+fn test_random() {
+    for solution in solutions() {
+        let size = solution.random_size.min(20);
+        let input = solution.random(2021, size);
+        assert_eq!(input, solution.random(2021, size));
+        if let Err(e) = solution.parse(&input) {
+            panic!("{}\n{}", e, input);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+// Something a solution did on the way to its answer, like day 4 drawing a
+// number.  Solutions emit these with trace!, which costs no more than a check
+// when no one is listening.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+type Sink = Box<dyn FnMut(&Event)>;
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+pub fn enabled() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+pub fn emit(event: Event) {
+    SINK.with(|sink| {
+        if let Some(sink) = sink.borrow_mut().as_mut() {
+            sink(&event)
+        }
+    })
+}
+
+// Puts back whichever sink was listening before, even on a panic
+struct Restore(Option<Sink>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        SINK.with(|sink| *sink.borrow_mut() = previous);
+    }
+}
+
+// Runs `f` with every event it emits on this thread handed to `sink`
+pub fn traced<R>(sink: impl FnMut(&Event) + 'static, f: impl FnOnce() -> R) -> R {
+    let previous = SINK.with(|current| current.borrow_mut().replace(Box::new(sink)));
+    let _restore = Restore(previous);
+    f()
+}
+
+// Runs `f`, keeping hold of the events it emits
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    let events = Rc::new(RefCell::new(vec![]));
+    let sink = Rc::clone(&events);
+    let result = traced(move |event| sink.borrow_mut().push(event.clone()), f);
+    let events = events.take();
+    (result, events)
+}
+
+// trace!("draw", number = n, board = ?board) emits an event named "draw",
+// showing fields with Display, or with Debug when marked with a ?
+macro_rules! trace {
+    (@fields [$($done:expr),*]) => {
+        vec![$($done),*]
+    };
+    (@fields [$($done:expr),*] $key:ident = ? $value:expr $(, $($rest:tt)*)?) => {
+        $crate::trace::trace!(
+            @fields [$($done,)* (stringify!($key), format!("{:?}", $value))] $($($rest)*)?
+        )
+    };
+    (@fields [$($done:expr),*] $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::trace::trace!(
+            @fields [$($done,)* (stringify!($key), $value.to_string())] $($($rest)*)?
+        )
+    };
+    ($name:literal $(, $($fields:tt)*)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit($crate::trace::Event {
+                name: $name,
+                fields: $crate::trace::trace!(@fields [] $($($fields)*)?),
+            });
+        }
+    };
+}
+pub(crate) use trace;
+
+#[test]
+fn test_trace() {
+    let ((), events) = collect(|| {
+        trace!("start");
+        trace!("draw", number = 7, board = ?vec![1, 2],);
+        assert!(enabled());
+    });
+    assert_eq!(
+        events.iter().map(Event::to_string).collect::<Vec<_>>(),
+        vec!["start", "draw number=7 board=[1, 2]"]
+    );
+
+    // Nobody listening, so nobody hears
+    assert!(!enabled());
+    trace!("lost", number = 1);
+
+    let (_, outer) = collect(|| {
+        trace!("outer");
+        let (_, inner) = collect(|| trace!("inner"));
+        assert_eq!(inner.len(), 1);
+        let _ = std::panic::catch_unwind(|| collect(|| panic!("lost in here")));
+        trace!("outer again");
+    });
+    assert_eq!(
+        outer.iter().map(|e| e.name).collect::<Vec<_>>(),
+        vec!["outer", "outer again"]
+    );
+}
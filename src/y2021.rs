@@ -0,0 +1,60 @@
+use crate::solver::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day18_parsed_trees;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const YEAR: u32 = 2021;
+
+pub(crate) fn solutions() -> Vec<Solution> {
+    vec![
+        Solution::of::<day01::Day01>(YEAR, 1),
+        Solution::of::<day02::Day02>(YEAR, 2),
+        Solution::of::<day03::Day03>(YEAR, 3),
+        Solution::of::<day04::Day04>(YEAR, 4),
+        Solution::of::<day05::Day05>(YEAR, 5),
+        Solution::of::<day06::Day06>(YEAR, 6),
+        Solution::of::<day07::Day07>(YEAR, 7),
+        Solution::of::<day08::Day08>(YEAR, 8),
+        Solution::of::<day09::Day09>(YEAR, 9),
+        Solution::of::<day10::Day10>(YEAR, 10),
+        Solution::of::<day11::Day11>(YEAR, 11),
+        Solution::of::<day12::Day12>(YEAR, 12),
+        Solution::of::<day13::Day13>(YEAR, 13),
+        Solution::of::<day14::Day14>(YEAR, 14),
+        Solution::of::<day15::Day15>(YEAR, 15),
+        Solution::of::<day16::Day16>(YEAR, 16),
+        Solution::of::<day17::Day17>(YEAR, 17),
+        Solution::of::<day18::Day18>(YEAR, 18),
+        Solution::of::<day19::Day19>(YEAR, 19),
+        Solution::of::<day20::Day20>(YEAR, 20),
+        Solution::of::<day21::Day21>(YEAR, 21),
+        Solution::of::<day22::Day22>(YEAR, 22),
+        Solution::of::<day23::Day23>(YEAR, 23),
+        Solution::of::<day24::Day24>(YEAR, 24),
+        Solution::of::<day25::Day25>(YEAR, 25),
+    ]
+}
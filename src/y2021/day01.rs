@@ -0,0 +1,352 @@
+use crate::error::{number, parse_lines, stream_lines, ParseError, StreamError};
+use crate::random::{lines, Random};
+use crate::solver::Solver;
+use itertools::process_results;
+use rand::Rng;
+use std::{cmp::Ordering, collections::VecDeque, io::BufRead, iter::Fuse};
+
+#[aoc_generator(day1)]
+fn generate(input: &str) -> Result<Vec<Depth>, ParseError> {
+    parse_lines(input, |l| number(1, l, l))
+}
+
+pub type Depth = u64;
+// Sums of many readings, which can go well past what a Depth holds
+pub type Total = u128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increase,
+    Decrease,
+    Plateau,
+}
+
+impl Trend {
+    fn between<T: Ord>(from: T, to: T) -> Self {
+        match to.cmp(&from) {
+            Ordering::Greater => Trend::Increase,
+            Ordering::Less => Trend::Decrease,
+            Ordering::Equal => Trend::Plateau,
+        }
+    }
+}
+
+// How the depth changes from one reading to the next.  Built up a reading at
+// a time, so logs of any length can be fed through, of depths or of the sums
+// of windows of them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Analysis<T = Depth> {
+    pub readings: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    // The most readings in a row each deeper, or shallower, than the one before
+    pub longest_rise: usize,
+    pub longest_fall: usize,
+    last: Option<T>,
+    rise: usize,
+    fall: usize,
+}
+
+impl<T: Ord + Copy> Analysis<T> {
+    pub fn add(&mut self, depth: T) {
+        self.readings += 1;
+        (self.rise, self.fall) = match self.last.map(|last| Trend::between(last, depth)) {
+            Some(Trend::Increase) => {
+                self.increases += 1;
+                (self.rise + 1, 1)
+            }
+            Some(Trend::Decrease) => {
+                self.decreases += 1;
+                (1, self.fall + 1)
+            }
+            Some(Trend::Plateau) => {
+                self.plateaus += 1;
+                (1, 1)
+            }
+            None => (1, 1),
+        };
+        self.longest_rise = self.longest_rise.max(self.rise);
+        self.longest_fall = self.longest_fall.max(self.fall);
+        self.last = Some(depth);
+    }
+}
+
+pub fn analyse<T: Ord + Copy + Default>(depths: impl IntoIterator<Item = T>) -> Analysis<T> {
+    let mut analysis = Analysis::default();
+    for depth in depths {
+        analysis.add(depth);
+    }
+    analysis
+}
+
+#[test]
+fn test_analyse() {
+    let analysis = analyse([199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+    assert_eq!(
+        (
+            analysis.readings,
+            analysis.increases,
+            analysis.decreases,
+            analysis.plateaus
+        ),
+        (10, 7, 2, 0)
+    );
+    assert_eq!((analysis.longest_rise, analysis.longest_fall), (4, 2));
+
+    let analysis = analyse([5, 5, 5, 4, 3, 2, 2]);
+    assert_eq!(
+        (analysis.increases, analysis.decreases, analysis.plateaus),
+        (0, 3, 3)
+    );
+    assert_eq!((analysis.longest_rise, analysis.longest_fall), (1, 4));
+
+    assert_eq!(analyse::<Depth>([]), Analysis::default());
+}
+
+// A window of readings in a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    // Where the window's first reading is in the log, from 0
+    pub start: usize,
+    pub sum: Total,
+    pub min: Depth,
+    pub max: Depth,
+    // How the sum moved from the window before, for all but the first
+    pub trend: Option<Trend>,
+}
+
+// Slides along a step at a time, so each reading is only added and taken
+// away once, whatever the width
+pub struct Summaries<I> {
+    depths: I,
+    width: usize,
+    window: VecDeque<Depth>,
+    sum: Total,
+    // The readings which could yet be the window's least, or its greatest, by
+    // where they are in the log.  Anything later and as low, or as high, would
+    // outlast them, so the depths only ever rise along lows and fall along
+    // highs, leaving the extreme at the front.
+    lows: VecDeque<(usize, Depth)>,
+    highs: VecDeque<(usize, Depth)>,
+    start: usize,
+    last_sum: Option<Total>,
+}
+
+impl<I> Summaries<I> {
+    fn push(&mut self, depth: Depth) {
+        let at = self.start + self.window.len();
+        while self.lows.back().is_some_and(|&(_, low)| low >= depth) {
+            self.lows.pop_back();
+        }
+        self.lows.push_back((at, depth));
+        while self.highs.back().is_some_and(|&(_, high)| high <= depth) {
+            self.highs.pop_back();
+        }
+        self.highs.push_back((at, depth));
+        self.sum = self
+            .sum
+            .checked_add(Total::from(depth))
+            .expect("more readings in a window than a Total can sum");
+        self.window.push_back(depth);
+    }
+
+    fn pop(&mut self) {
+        let Some(depth) = self.window.pop_front() else {
+            return;
+        };
+        self.sum = self
+            .sum
+            .checked_sub(Total::from(depth))
+            .expect("taking away a reading that was never added");
+        for extremes in [&mut self.lows, &mut self.highs] {
+            if extremes.front().is_some_and(|&(at, _)| at == self.start) {
+                extremes.pop_front();
+            }
+        }
+        self.start += 1;
+    }
+}
+
+impl<I: Iterator<Item = Depth>> Iterator for Summaries<I> {
+    type Item = Summary;
+
+    fn next(&mut self) -> Option<Summary> {
+        if self.width == 0 {
+            return None;
+        }
+        while self.window.len() < self.width {
+            let depth = self.depths.next()?;
+            self.push(depth);
+        }
+        let summary = Summary {
+            start: self.start,
+            sum: self.sum,
+            min: self.lows.front()?.1,
+            max: self.highs.front()?.1,
+            trend: self.last_sum.map(|last| Trend::between(last, self.sum)),
+        };
+        self.pop();
+        self.last_sum = Some(summary.sum);
+        Some(summary)
+    }
+}
+
+// Each run of `width` readings in turn, holding no more than the one window
+// of readings at once
+pub fn summaries<I: IntoIterator<Item = Depth>>(
+    depths: I,
+    width: usize,
+) -> Summaries<Fuse<I::IntoIter>> {
+    Summaries {
+        depths: depths.into_iter().fuse(),
+        width,
+        window: VecDeque::with_capacity(width),
+        sum: 0,
+        lows: VecDeque::new(),
+        highs: VecDeque::new(),
+        start: 0,
+        last_sum: None,
+    }
+}
+
+pub fn window_sums(
+    depths: impl IntoIterator<Item = Depth>,
+    width: usize,
+) -> impl Iterator<Item = Total> {
+    summaries(depths, width).map(|summary| summary.sum)
+}
+
+#[test]
+fn test_summaries() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(
+        window_sums(depths, 3).collect::<Vec<_>>(),
+        vec![607, 618, 618, 617, 647, 716, 769, 792]
+    );
+    assert_eq!(
+        window_sums(depths, 1).collect::<Vec<_>>(),
+        depths.map(Total::from)
+    );
+    assert_eq!(window_sums(depths, 10).collect::<Vec<_>>(), vec![2256]);
+    assert_eq!(window_sums(depths, 11).count(), 0);
+    assert_eq!(window_sums(depths, 0).count(), 0);
+
+    let fours: Vec<_> = summaries(depths, 4).collect();
+    assert_eq!(
+        fours[3],
+        Summary {
+            start: 3,
+            sum: 857,
+            min: 200,
+            max: 240,
+            trend: Some(Trend::Increase)
+        }
+    );
+    assert_eq!(
+        summaries(depths, 3)
+            .take(4)
+            .map(|s| s.trend)
+            .collect::<Vec<_>>(),
+        vec![
+            None,
+            Some(Trend::Increase),
+            Some(Trend::Plateau),
+            Some(Trend::Decrease)
+        ]
+    );
+
+    // Sums deeper than a Depth goes, and the extremes of every window
+    assert_eq!(
+        window_sums([Depth::MAX; 3], 2).collect::<Vec<_>>(),
+        vec![2 * Total::from(Depth::MAX); 2]
+    );
+    let jagged = [5, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9];
+    for width in 1..=jagged.len() {
+        for summary in summaries(jagged, width) {
+            let window = &jagged[summary.start..summary.start + width];
+            assert_eq!(summary.min, *window.iter().min().unwrap());
+            assert_eq!(summary.max, *window.iter().max().unwrap());
+        }
+    }
+}
+
+#[aoc(day1, part1)]
+fn increases(depths: &[Depth]) -> usize {
+    analyse(depths.iter().copied()).increases
+}
+
+#[test]
+fn test_increases() {
+    assert_eq!(
+        increases(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
+        7
+    );
+}
+
+#[aoc(day1, part2)]
+fn sliding_increases(depths: &[Depth]) -> usize {
+    analyse(window_sums(depths.iter().copied(), 3)).increases
+}
+
+#[test]
+fn test_sliding_increases() {
+    assert_eq!(
+        sliding_increases(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
+        5
+    );
+}
+
+#[test]
+fn test_streamed_analysis() {
+    // Deeper than a u32 goes
+    let log = "5000000000\n5000000001\n5000000001\n4999999999\n";
+    let depths = stream_lines(log.as_bytes(), |l| number::<Depth>(1, l, l));
+    let analysis = process_results(depths, |depths| analyse(depths)).unwrap();
+    assert_eq!(
+        (analysis.increases, analysis.decreases, analysis.plateaus),
+        (1, 1, 1)
+    );
+}
+
+// A sounding per line, mostly getting deeper
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut depth: i64 = rng.gen_range(100..200);
+    lines(rng, size, |rng| {
+        depth = (depth + rng.gen_range(-10..=30)).max(0);
+        depth.to_string()
+    })
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Vec<Depth>;
+    const RANDOM_SIZE: usize = 2000;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        increases(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        sliding_increases(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn stream(reader: &mut dyn BufRead, part: u32) -> Option<Result<String, StreamError>> {
+        let depths = stream_lines(reader, |l| number(1, l, l));
+        let count = match part {
+            1 => process_results(depths, |depths| analyse(depths).increases),
+            2 => process_results(depths, |depths| analyse(window_sums(depths, 3)).increases),
+            _ => return None,
+        };
+        Some(count.map(|count| count.to_string()))
+    }
+}
@@ -0,0 +1,562 @@
+use crate::error::{column_of, number, stream_lines, ParseError, StreamError};
+use crate::random::{lines, Random};
+use crate::solver::Solver;
+use itertools::{process_results, Itertools};
+use rand::Rng;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Back(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl TryFrom<&str> for Command {
+    type Error = ParseError;
+
+    fn try_from(command: &str) -> Result<Self, Self::Error> {
+        let (verb, distance) = command
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(2, command, "", "a command and a distance"))?;
+        let d = number(2, command, distance)?;
+        match verb {
+            "forward" => Ok(Command::Forward(d)),
+            "back" => Ok(Command::Back(d)),
+            "down" => Ok(Command::Down(d)),
+            "up" => Ok(Command::Up(d)),
+            _ => Err(ParseError::at(
+                2,
+                command,
+                verb,
+                "forward, back, down or up",
+            )),
+        }
+    }
+}
+
+// A navigation script: the puzzle's commands, along with
+//
+//     back 3          # the reverse of forward
+//     repeat 4 {      # the commands up to the matching }, 4 times over
+//         forward 2
+//     }
+//
+// and comments from # to the end of the line.  The puzzle input is a script
+// with none of these.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Command(Command),
+    Repeat(u32, Vec<Step>),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Script(pub Vec<Step>);
+
+// What's left of a line once its comment is gone
+fn uncommented(line: &str) -> &str {
+    line.split_once('#').map_or(line, |(code, _)| code).trim()
+}
+
+// The command that's all the code on a line, errors counting their columns
+// from the start of the line
+fn command(line: &str, code: &str) -> Result<Command, ParseError> {
+    Command::try_from(code).map_err(|mut e| {
+        e.column += column_of(line, code) - 1;
+        e
+    })
+}
+
+impl TryFrom<&str> for Script {
+    type Error = ParseError;
+
+    fn try_from(script: &str) -> Result<Self, Self::Error> {
+        // The repeats still open: how many times, the line each began on, and
+        // the steps from around it to go back to once it closes
+        let mut open: Vec<(u32, usize, Vec<Step>)> = vec![];
+        let mut steps = vec![];
+        for (n, line) in script.lines().enumerate() {
+            let code = uncommented(line);
+            if code.is_empty() {
+                continue;
+            } else if code == "}" {
+                let (times, _, outer) = open
+                    .pop()
+                    .ok_or_else(|| ParseError::at(2, line, code, "a command").on_line(n + 1))?;
+                let body = std::mem::replace(&mut steps, outer);
+                steps.push(Step::Repeat(times, body));
+            } else if let Some(times) = code.strip_prefix("repeat ") {
+                let times = times.strip_suffix('{').ok_or_else(|| {
+                    ParseError::at(2, line, &code[code.len()..], "{ to begin the repeat")
+                        .on_line(n + 1)
+                })?;
+                let times = number(2, line, times.trim()).map_err(|e| e.on_line(n + 1))?;
+                open.push((times, n + 1, std::mem::take(&mut steps)));
+            } else {
+                steps.push(Step::Command(
+                    command(line, code).map_err(|e| e.on_line(n + 1))?,
+                ));
+            }
+        }
+        match open.pop() {
+            Some((_, opened, _)) => Err(ParseError::new(
+                2,
+                script.lines().count(),
+                script.lines().last().map_or(0, str::len) + 1,
+                format!("a }} closing the repeat on line {}", opened),
+            )),
+            None => Ok(Script(steps)),
+        }
+    }
+}
+
+impl Script {
+    // Every command in the order they're carried out, repeats and all
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        fn unrolled(steps: &[Step]) -> Box<dyn Iterator<Item = Command> + '_> {
+            Box::new(steps.iter().flat_map(|step| match step {
+                Step::Command(command) => Box::new(std::iter::once(*command)),
+                Step::Repeat(times, body) => {
+                    Box::new((0..*times).flat_map(move |_| unrolled(body)))
+                        as Box<dyn Iterator<Item = Command>>
+                }
+            }))
+        }
+        unrolled(&self.0)
+    }
+}
+
+#[aoc_generator(day2)]
+fn generate(input: &str) -> Result<Script, ParseError> {
+    Script::try_from(input)
+}
+
+#[test]
+fn test_command_errors() {
+    assert_eq!(
+        generate("forward 5\nsideways 2").err(),
+        Some(ParseError::new(2, 2, 1, "forward, back, down or up"))
+    );
+    assert_eq!(
+        generate("forward 5\ndown x").err(),
+        Some(ParseError::new(2, 2, 6, "a number"))
+    );
+    assert_eq!(
+        generate("forward").err(),
+        Some(ParseError::new(2, 1, 8, "a command and a distance"))
+    );
+}
+
+#[test]
+fn test_script() {
+    let script = generate(
+        "# A dive\ndown 2\nrepeat 2 {\n  forward 3  # and again\n  repeat 3 {\n    up 1\n  }\n}\n\nback 1\n",
+    )
+    .unwrap();
+    assert_eq!(
+        script,
+        Script(vec![
+            Step::Command(Command::Down(2)),
+            Step::Repeat(
+                2,
+                vec![
+                    Step::Command(Command::Forward(3)),
+                    Step::Repeat(3, vec![Step::Command(Command::Up(1))])
+                ]
+            ),
+            Step::Command(Command::Back(1))
+        ])
+    );
+    assert_eq!(script.commands().count(), 10);
+    assert_eq!(
+        Script(vec![Step::Repeat(0, vec![Step::Command(Command::Up(1))])])
+            .commands()
+            .count(),
+        0
+    );
+
+    assert_eq!(
+        generate("repeat 2 {\n  forward 1\n  sideways 2\n}").err(),
+        Some(ParseError::new(2, 3, 3, "forward, back, down or up"))
+    );
+    assert_eq!(
+        generate("repeat twice {\nforward 1\n}").err(),
+        Some(ParseError::new(2, 1, 8, "a number"))
+    );
+    assert_eq!(
+        generate("repeat 2\nforward 1").err(),
+        Some(ParseError::new(2, 1, 9, "{ to begin the repeat"))
+    );
+    assert_eq!(
+        generate("forward 1\n}").err(),
+        Some(ParseError::new(2, 2, 1, "a command"))
+    );
+    assert_eq!(
+        generate("repeat 2 {\nforward 1").err(),
+        Some(ParseError::new(
+            2,
+            2,
+            10,
+            "a } closing the repeat on line 1"
+        ))
+    );
+}
+
+// How the sub moves: part 1's, where up and down change the depth, or the
+// aimed physics from part 2, where they tilt the sub instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Physics {
+    Simple,
+    Aimed,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    pub fn moved(self, command: Command, physics: Physics) -> Self {
+        let Position {
+            mut horizontal,
+            mut depth,
+            mut aim,
+        } = self;
+        match (command, physics) {
+            (Command::Forward(x), Physics::Simple) => horizontal += x,
+            (Command::Back(x), Physics::Simple) => horizontal -= x,
+            (Command::Down(d), Physics::Simple) => depth += d,
+            (Command::Up(d), Physics::Simple) => depth -= d,
+            (Command::Forward(x), Physics::Aimed) => {
+                horizontal += x;
+                depth += aim * x;
+            }
+            (Command::Back(x), Physics::Aimed) => {
+                horizontal -= x;
+                depth -= aim * x;
+            }
+            (Command::Down(d), Physics::Aimed) => aim += d,
+            (Command::Up(d), Physics::Aimed) => aim -= d,
+        }
+        Position {
+            horizontal,
+            depth,
+            aim,
+        }
+    }
+
+    // The puzzle's answer
+    pub fn product(&self) -> i64 {
+        self.horizontal * self.depth
+    }
+}
+
+// Where the sub is after each command in turn, starting from the surface
+pub fn positions(
+    commands: impl IntoIterator<Item = Command>,
+    physics: Physics,
+) -> impl Iterator<Item = Position> {
+    commands
+        .into_iter()
+        .scan(Position::default(), move |position, command| {
+            *position = position.moved(command, physics);
+            Some(*position)
+        })
+}
+
+// Every position the sub passes through running a script, the surface it
+// starts from first, to go back over a dive step by step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    pub physics: Physics,
+    positions: Vec<Position>,
+}
+
+impl Trajectory {
+    pub fn of(script: &Script, physics: Physics) -> Self {
+        Trajectory {
+            physics,
+            positions: std::iter::once(Position::default())
+                .chain(positions(script.commands(), physics))
+                .collect(),
+        }
+    }
+
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    // How many commands were carried out
+    pub fn steps(&self) -> usize {
+        self.positions.len() - 1
+    }
+
+    // Where the sub was after `step` commands, step 0 being the surface
+    pub fn seek(&self, step: usize) -> Option<Position> {
+        self.positions.get(step).copied()
+    }
+
+    pub fn end(&self) -> Position {
+        *self.positions.last().unwrap()
+    }
+
+    pub fn max_depth(&self) -> i64 {
+        self.positions.iter().map(|p| p.depth).max().unwrap()
+    }
+
+    // The first step where the dive strays from an expected profile, as read
+    // by read_csv, or where one of them ends before the other
+    pub fn diverges_from(&self, expected: &[Position]) -> Option<usize> {
+        self.positions
+            .iter()
+            .zip(expected)
+            .position(|(actual, expected)| actual != expected)
+            .or_else(|| {
+                (self.positions.len() != expected.len())
+                    .then(|| self.positions.len().min(expected.len()))
+            })
+    }
+
+    pub fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", CSV_HEADER)?;
+        for (step, p) in self.positions.iter().enumerate() {
+            writeln!(out, "{},{},{},{}", step, p.horizontal, p.depth, p.aim)?;
+        }
+        Ok(())
+    }
+
+    // The path seen from the side, depth going down the page.  Dives are far
+    // deeper than they are long, so the picture is stretched to fit.
+    pub fn svg(&self) -> String {
+        let (left, right) = self
+            .positions
+            .iter()
+            .map(|p| p.horizontal)
+            .minmax()
+            .into_option()
+            .unwrap();
+        let (top, bottom) = self
+            .positions
+            .iter()
+            .map(|p| p.depth)
+            .minmax()
+            .into_option()
+            .unwrap();
+        let points = self
+            .positions
+            .iter()
+            .map(|p| format!("{},{}", p.horizontal, p.depth))
+            .join(" ");
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n\
+             <polyline fill=\"none\" stroke=\"#0082c8\" stroke-width=\"2\" vector-effect=\"non-scaling-stroke\" points=\"{}\"/>\n\
+             </svg>\n",
+            left,
+            top,
+            (right - left).max(1),
+            (bottom - top).max(1),
+            points
+        )
+    }
+}
+
+const CSV_HEADER: &str = "step,horizontal,depth,aim";
+
+// A dive profile as written by Trajectory::write_csv, to check a dive against
+pub fn read_csv(profile: &str) -> Result<Vec<Position>, ParseError> {
+    let mut lines = profile.lines();
+    if lines.next() != Some(CSV_HEADER) {
+        return Err(ParseError::new(2, 1, 1, CSV_HEADER));
+    }
+    lines
+        .enumerate()
+        .map(|(step, line)| {
+            let fields: Vec<_> = line.split(',').collect();
+            let [at, horizontal, depth, aim] = fields[..] else {
+                return Err(ParseError::at(2, line, "", "four fields").on_line(step + 2));
+            };
+            let field = |token| number(2, line, token).map_err(|e| e.on_line(step + 2));
+            if field(at)? != step as i64 {
+                return Err(ParseError::at(2, line, at, format!("step {}", step)).on_line(step + 2));
+            }
+            Ok(Position {
+                horizontal: field(horizontal)?,
+                depth: field(depth)?,
+                aim: field(aim)?,
+            })
+        })
+        .collect()
+}
+
+fn destination(commands: impl IntoIterator<Item = Command>, physics: Physics) -> Position {
+    positions(commands, physics).last().unwrap_or_default()
+}
+
+#[aoc(day2, part1)]
+fn position(script: &Script) -> i64 {
+    destination(script.commands(), Physics::Simple).product()
+}
+
+const EXAMPLE: &str = include_str!("day2_example1.txt");
+
+#[test]
+fn test_position() {
+    assert_eq!(position(&generate(EXAMPLE).unwrap()), 150);
+    assert_eq!(
+        Day02::stream(&mut EXAMPLE.as_bytes(), 1).and_then(Result::ok),
+        Some(String::from("150"))
+    );
+
+    let streamed = |script: &str| {
+        Day02::stream(&mut script.as_bytes(), 1)
+            .unwrap()
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(
+        streamed("forward 5\n    down x  # deeper\n"),
+        "day 2 input, line 2, column 10: expected a number"
+    );
+    assert_eq!(
+        streamed("forward 5\n  repeat 2 {\n    down 1\n  }\n"),
+        "day 2 input, line 2, column 3: expected a command, as repeats can't be streamed"
+    );
+}
+
+#[aoc(day2, part2)]
+fn position_aimed(script: &Script) -> i64 {
+    destination(script.commands(), Physics::Aimed).product()
+}
+
+#[test]
+fn test_position_aimed() {
+    assert_eq!(position_aimed(&generate(EXAMPLE).unwrap()), 900);
+}
+
+#[test]
+fn test_trajectory() {
+    let script = generate(EXAMPLE).unwrap();
+    let aimed = Trajectory::of(&script, Physics::Aimed);
+    assert_eq!(aimed.steps(), 6);
+    assert_eq!(aimed.seek(0), Some(Position::default()));
+    assert_eq!(
+        aimed.seek(4),
+        Some(Position {
+            horizontal: 13,
+            depth: 40,
+            aim: 2
+        })
+    );
+    assert_eq!(aimed.seek(7), None);
+    assert_eq!(aimed.end().product(), 900);
+    assert_eq!(aimed.max_depth(), 60);
+    assert_eq!(Trajectory::of(&script, Physics::Simple).max_depth(), 10);
+
+    // Backing up undoes going forward, whichever the physics
+    let there_and_back = generate("down 5\nforward 8\nback 8").unwrap();
+    for physics in [Physics::Simple, Physics::Aimed] {
+        let path = Trajectory::of(&there_and_back, physics);
+        assert_eq!(path.seek(3), path.seek(1));
+    }
+}
+
+#[test]
+fn test_trajectory_export() {
+    let trajectory = Trajectory::of(
+        &generate("forward 2\ndown 1\nforward 3").unwrap(),
+        Physics::Aimed,
+    );
+    let mut csv = vec![];
+    trajectory.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(
+        csv,
+        "step,horizontal,depth,aim\n0,0,0,0\n1,2,0,0\n2,2,0,1\n3,5,3,1\n"
+    );
+
+    let profile = read_csv(&csv).unwrap();
+    assert_eq!(trajectory.diverges_from(&profile), None);
+    assert_eq!(trajectory.diverges_from(&profile[..2]), Some(2));
+    let mut deeper = profile.clone();
+    deeper[3].depth = 4;
+    assert_eq!(trajectory.diverges_from(&deeper), Some(3));
+
+    assert_eq!(
+        read_csv("step,horizontal,depth,aim\n0,0,0,0\n2,2,0,0").err(),
+        Some(ParseError::new(2, 3, 1, "step 1"))
+    );
+    assert_eq!(
+        read_csv("step,horizontal,depth,aim\n0,0,0").err(),
+        Some(ParseError::new(2, 2, 6, "four fields"))
+    );
+    assert_eq!(
+        read_csv("horizontal,depth").err(),
+        Some(ParseError::new(2, 1, 1, "step,horizontal,depth,aim"))
+    );
+
+    let svg = trajectory.svg();
+    assert!(svg.contains("viewBox=\"0 0 5 3\""));
+    assert!(svg.contains("points=\"0,0 2,0 2,0 5,3\""));
+}
+
+// Going down more than up, so the sub stays under water
+fn random_input(rng: &mut Random, size: usize) -> String {
+    lines(rng, size, |rng| {
+        let verb = match rng.gen_range(0..10) {
+            0..=3 => "forward",
+            4..=7 => "down",
+            _ => "up",
+        };
+        format!("{} {}", verb, rng.gen_range(1..=9))
+    })
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = Script;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const RANDOM_SIZE: usize = 1000;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        position(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        position_aimed(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn stream(reader: &mut dyn BufRead, part: u32) -> Option<Result<String, StreamError>> {
+        if part != 1 {
+            return None;
+        }
+        // Commands and comments only, as a repeat would need its body held
+        let commands = stream_lines(reader, |line| match uncommented(line) {
+            "" => Ok(None),
+            code if code == "}" || code.starts_with("repeat ") => Err(ParseError::at(
+                2,
+                line,
+                code,
+                "a command, as repeats can't be streamed",
+            )),
+            code => command(line, code).map(Some),
+        });
+        Some(
+            process_results(commands, |commands| {
+                destination(commands.flatten(), Physics::Simple).product()
+            })
+            .map(|product| product.to_string()),
+        )
+    }
+}
@@ -0,0 +1,507 @@
+use crate::error::{parse_lines, stream_lines, ParseError, StreamError};
+use crate::random::Random;
+use crate::solver::Solver;
+use rand::seq::index::sample;
+use std::{cmp::Ordering, io::BufRead};
+
+// Readings are packed into the bits of a u128, the first column the most
+// significant, so reports can be as wide as 128 columns
+pub type Reading = u128;
+pub const MAX_WIDTH: usize = Reading::BITS as usize;
+
+// Every reading is as wide as the first
+fn reading(line: &str, width: usize) -> Result<Reading, ParseError> {
+    if line.is_empty() {
+        return Err(ParseError::new(3, 1, 1, "0 or 1"));
+    }
+    if let Some((i, _)) = line.char_indices().find(|(_, c)| !matches!(c, '0' | '1')) {
+        return Err(ParseError::at(3, line, &line[i..], "0 or 1"));
+    }
+    if line.len() != width {
+        let column = line.len().min(width) + 1;
+        return Err(ParseError::new(3, 1, column, format!("{} bits", width)));
+    }
+    if width > MAX_WIDTH {
+        return Err(ParseError::new(
+            3,
+            1,
+            MAX_WIDTH + 1,
+            format!("at most {} bits", MAX_WIDTH),
+        ));
+    }
+    Ok(Reading::from_str_radix(line, 2).unwrap())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Common {
+    Zero,
+    One,
+    Tie,
+}
+
+// The bits in one column of the readings, or of those still in the running
+// when selecting a rating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tally {
+    pub zeroes: usize,
+    pub ones: usize,
+}
+
+impl Tally {
+    pub fn majority(&self) -> Common {
+        match self.ones.cmp(&self.zeroes) {
+            Ordering::Greater => Common::One,
+            Ordering::Less => Common::Zero,
+            Ordering::Equal => Common::Tie,
+        }
+    }
+
+    pub fn minority(&self) -> Common {
+        match self.majority() {
+            Common::One => Common::Zero,
+            Common::Zero => Common::One,
+            Common::Tie => Common::Tie,
+        }
+    }
+
+    // In bits, so 1 for a column evenly split and 0 for one all the same
+    pub fn entropy(&self) -> f64 {
+        let total = (self.zeroes + self.ones) as f64;
+        [self.zeroes, self.ones]
+            .into_iter()
+            .filter(|&n| n > 0)
+            .map(|n| {
+                let p = n as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+fn bits(columns: impl Iterator<Item = bool>) -> Reading {
+    columns.fold(0, |reading, bit| (reading << 1) | Reading::from(bit))
+}
+
+// How many ones each column has had, which is all part 1 needs to keep of
+// the readings
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BitCounts {
+    pub width: usize,
+    pub ones: Vec<usize>,
+    pub readings: usize,
+}
+
+impl BitCounts {
+    pub fn new(width: usize) -> Self {
+        BitCounts {
+            width,
+            ones: vec![0; width],
+            readings: 0,
+        }
+    }
+
+    pub fn add(&mut self, reading: Reading) {
+        let mut bits = reading;
+        while bits != 0 {
+            let bit = bits.trailing_zeros() as usize;
+            self.ones[self.width - 1 - bit] += 1;
+            bits &= bits - 1;
+        }
+        self.readings += 1;
+    }
+
+    pub fn tally(&self, column: usize) -> Tally {
+        Tally {
+            zeroes: self.readings - self.ones[column],
+            ones: self.ones[column],
+        }
+    }
+
+    pub fn tallies(&self) -> impl Iterator<Item = Tally> + '_ {
+        (0..self.width).map(|column| self.tally(column))
+    }
+
+    // The puzzle settles ties in favour of 0 for gamma, and so 1 for epsilon
+    pub fn gamma(&self) -> Reading {
+        bits(self.tallies().map(|t| t.majority() == Common::One))
+    }
+
+    pub fn epsilon(&self) -> Reading {
+        bits(self.tallies().map(|t| t.minority() != Common::Zero))
+    }
+
+    // None when it's too big for even a u128, as reports over 64 bits wide
+    // can be
+    pub fn power(&self) -> Option<Reading> {
+        self.gamma().checked_mul(self.epsilon())
+    }
+}
+
+// The whole report, sorted, so the readings that share the first few bits
+// are always next to each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    readings: Vec<Reading>,
+    counts: BitCounts,
+}
+
+impl DiagnosticReport {
+    // Readings as parsed from a report `width` columns wide, so none can
+    // have a bit set past that, and it can be no wider than a Reading
+    pub fn new(width: usize, mut readings: Vec<Reading>) -> Result<Self, ParseError> {
+        if width > MAX_WIDTH {
+            return Err(ParseError::new(
+                3,
+                1,
+                MAX_WIDTH + 1,
+                format!("at most {} bits", MAX_WIDTH),
+            ));
+        }
+        if let Some(n) = readings
+            .iter()
+            .position(|&r| width < MAX_WIDTH && r >> width != 0)
+        {
+            return Err(ParseError::new(3, n + 1, 1, format!("{} bits", width)));
+        }
+        let mut counts = BitCounts::new(width);
+        for &reading in &readings {
+            counts.add(reading);
+        }
+        readings.sort_unstable();
+        Ok(DiagnosticReport { readings, counts })
+    }
+
+    pub fn width(&self) -> usize {
+        self.counts.width
+    }
+
+    pub fn readings(&self) -> &[Reading] {
+        &self.readings
+    }
+
+    pub fn counts(&self) -> &BitCounts {
+        &self.counts
+    }
+
+    pub fn majority(&self) -> Vec<Common> {
+        self.counts.tallies().map(|t| t.majority()).collect()
+    }
+
+    pub fn minority(&self) -> Vec<Common> {
+        self.counts.tallies().map(|t| t.minority()).collect()
+    }
+
+    pub fn entropy(&self) -> Vec<f64> {
+        self.counts.tallies().map(|t| t.entropy()).collect()
+    }
+
+    // Whittles the readings down a column at a time to the one each criterion
+    // picks, all of them together.  Given the bits in the next column of the
+    // readings still in the running, a criterion says whether to keep those
+    // with a 1 there.  As the readings left always share the columns so far,
+    // they're a run of the sorted readings that splits where the next column
+    // turns from 0 to 1.
+    pub fn select_each(&self, criteria: &[&dyn Fn(Tally) -> bool]) -> Option<Vec<Reading>> {
+        if self.readings.is_empty() {
+            return None;
+        }
+        let mut selections = vec![0..self.readings.len(); criteria.len()];
+        for column in 0..self.width() {
+            let bit = self.width() - 1 - column;
+            for (candidates, keep_ones) in selections.iter_mut().zip(criteria) {
+                if candidates.len() == 1 {
+                    continue;
+                }
+                let run = &self.readings[candidates.clone()];
+                let split = candidates.start + run.partition_point(|r| r >> bit & 1 == 0);
+                let (zeroes, ones) = (candidates.start..split, split..candidates.end);
+                let tally = Tally {
+                    zeroes: zeroes.len(),
+                    ones: ones.len(),
+                };
+                // When they all share this bit, picking the other would leave
+                // none
+                *candidates = match keep_ones(tally) {
+                    _ if zeroes.is_empty() => ones,
+                    _ if ones.is_empty() => zeroes,
+                    true => ones,
+                    false => zeroes,
+                };
+            }
+        }
+        // Any left over are the same reading more than once
+        Some(selections.iter().map(|s| self.readings[s.start]).collect())
+    }
+
+    pub fn select(&self, keep_ones: impl Fn(Tally) -> bool) -> Option<Reading> {
+        self.select_each(&[&keep_ones]).map(|selected| selected[0])
+    }
+
+    // The oxygen generator and CO2 scrubber ratings, found together, which
+    // settle ties in favour of 1 and 0 respectively
+    pub fn ratings(&self) -> Option<(Reading, Reading)> {
+        let oxygen = |t: Tally| t.majority() != Common::Zero;
+        let co2 = |t: Tally| t.minority() == Common::One;
+        self.select_each(&[&oxygen, &co2])
+            .map(|selected| (selected[0], selected[1]))
+    }
+}
+
+fn no_readings() -> ParseError {
+    ParseError::new(3, 1, 1, "a reading")
+}
+
+impl TryFrom<&str> for DiagnosticReport {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let width = input.lines().next().map_or(0, |l| l.len());
+        let readings = parse_lines(input, |line| reading(line, width))?;
+        if readings.is_empty() {
+            return Err(no_readings());
+        }
+        DiagnosticReport::new(width, readings)
+    }
+}
+
+// For readings still kept as strings, one to a line
+impl TryFrom<&[String]> for DiagnosticReport {
+    type Error = ParseError;
+
+    fn try_from(lines: &[String]) -> Result<Self, Self::Error> {
+        let width = lines.first().map_or(0, |l| l.len());
+        let readings = lines
+            .iter()
+            .enumerate()
+            .map(|(n, line)| reading(line, width).map_err(|e| e.on_line(n + 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if readings.is_empty() {
+            return Err(no_readings());
+        }
+        DiagnosticReport::new(width, readings)
+    }
+}
+
+#[aoc_generator(day3)]
+fn generate(input: &str) -> Result<DiagnosticReport, ParseError> {
+    DiagnosticReport::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("00100\n11110\n10120").err(),
+        Some(ParseError::new(3, 3, 4, "0 or 1"))
+    );
+    assert_eq!(
+        generate("00100\n1111").err(),
+        Some(ParseError::new(3, 2, 5, "5 bits"))
+    );
+    assert_eq!(
+        generate("\n01\n10").err(),
+        Some(ParseError::new(3, 1, 1, "0 or 1"))
+    );
+    assert_eq!(
+        generate("01\n\n10").err(),
+        Some(ParseError::new(3, 2, 1, "0 or 1"))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(3, 1, 1, "a reading"))
+    );
+    assert_eq!(
+        generate(&"1".repeat(129)).err(),
+        Some(ParseError::new(3, 1, 129, "at most 128 bits"))
+    );
+}
+
+fn answer(product: Option<Reading>) -> String {
+    product.map_or_else(|| String::from("too big for a u128"), |n| n.to_string())
+}
+
+#[aoc(day3, part1)]
+fn battery(report: &DiagnosticReport) -> String {
+    answer(report.counts().power())
+}
+
+fn stream_battery(reader: impl BufRead) -> Result<String, StreamError> {
+    let mut counts = None;
+    for reading in stream_lines(reader, |line| {
+        let counts = counts.get_or_insert_with(|| BitCounts::new(line.len()));
+        counts.add(reading(line, counts.width)?);
+        Ok(())
+    }) {
+        reading?;
+    }
+    let counts = counts.ok_or_else(no_readings)?;
+    Ok(answer(counts.power()))
+}
+
+const EXAMPLE: &str = include_str!("day3_example1.txt");
+
+#[test]
+fn test_battery() {
+    let report = generate(EXAMPLE).unwrap();
+    assert_eq!(report.counts().ones, vec![7, 5, 8, 7, 5]);
+    assert_eq!(report.counts().gamma(), 22);
+    assert_eq!(report.counts().epsilon(), 9);
+    assert_eq!(battery(&report), "198");
+    assert_eq!(stream_battery(EXAMPLE.as_bytes()).unwrap(), "198");
+    assert_eq!(
+        stream_battery("00100\n1111\n".as_bytes())
+            .unwrap_err()
+            .to_string(),
+        "day 3 input, line 2, column 5: expected 5 bits"
+    );
+    assert_eq!(
+        stream_battery("".as_bytes()).unwrap_err().to_string(),
+        "day 3 input, line 1, column 1: expected a reading"
+    );
+}
+
+#[test]
+fn test_ratings() {
+    assert_eq!(generate(EXAMPLE).unwrap().ratings(), Some((23, 10)));
+    assert_eq!(generate("10\n11").unwrap().ratings(), Some((3, 2)));
+    assert_eq!(
+        generate("0110\n0110\n0001").unwrap().ratings(),
+        Some((6, 1))
+    );
+    assert_eq!(DiagnosticReport::new(4, vec![]).unwrap().ratings(), None);
+}
+
+#[test]
+fn test_new_report_widths() {
+    assert_eq!(
+        DiagnosticReport::new(4, vec![0b1010, 0b10000]).err(),
+        Some(ParseError::new(3, 2, 1, "4 bits"))
+    );
+    assert_eq!(
+        DiagnosticReport::new(129, vec![1]).err(),
+        Some(ParseError::new(3, 1, 129, "at most 128 bits"))
+    );
+    assert!(DiagnosticReport::new(128, vec![Reading::MAX]).is_ok());
+}
+
+#[test]
+fn test_wide_reports() {
+    let report = DiagnosticReport::new(64, vec![u64::MAX as Reading, 1 << 63, 1 << 63]).unwrap();
+    assert_eq!(report.counts().gamma(), 1 << 63);
+    assert_eq!(report.counts().epsilon(), (1 << 63) - 1);
+    assert_eq!(report.ratings(), Some((1 << 63, u64::MAX as Reading)));
+
+    let widest =
+        DiagnosticReport::try_from(format!("{}\n{}0", "1".repeat(128), "0".repeat(127)).as_str())
+            .unwrap();
+    assert_eq!(widest.counts().ones, vec![1; 128]);
+    assert_eq!(widest.counts().gamma(), 0);
+    assert_eq!(widest.counts().epsilon(), Reading::MAX);
+    assert_eq!(battery(&widest), "0");
+    assert_eq!(widest.ratings(), Some((Reading::MAX, 0)));
+}
+
+#[test]
+fn test_statistics() {
+    use Common::*;
+
+    let report = generate(EXAMPLE).unwrap();
+    assert_eq!(report.majority(), vec![One, Zero, One, One, Zero]);
+    assert_eq!(report.minority(), vec![Zero, One, Zero, Zero, One]);
+    let tied = generate("10\n11\n00\n01").unwrap();
+    assert_eq!(tied.majority(), vec![Tie, Tie]);
+    assert_eq!(tied.minority(), vec![Tie, Tie]);
+    assert_eq!(tied.counts().gamma(), 0);
+    assert_eq!(tied.counts().epsilon(), 3);
+
+    assert_eq!(tied.entropy(), vec![1.0, 1.0]);
+    let entropy = generate("11\n10\n10\n10").unwrap().entropy();
+    assert_eq!(entropy[0], 0.0);
+    assert!((entropy[1] - 0.811278).abs() < 1e-6);
+}
+
+#[test]
+fn test_select() {
+    let report = generate(EXAMPLE).unwrap();
+    // The readings with the most ones, whichever way that goes
+    assert_eq!(report.select(|_| true), Some(0b11110));
+    assert_eq!(report.select(|_| false), Some(0b00010));
+    // Oxygen, but with ties going to 0
+    assert_eq!(report.select(|t| t.majority() == Common::One), Some(22));
+    let tied = generate("10\n11\n00\n01").unwrap();
+    assert_eq!(tied.ratings(), Some((3, 0)));
+}
+
+#[aoc(day3, part2)]
+fn lifesupport(report: &DiagnosticReport) -> String {
+    match report.ratings() {
+        Some((oxygen, co2)) => answer(oxygen.checked_mul(co2)),
+        None => String::from("no readings"),
+    }
+}
+
+#[test]
+fn test_lifesupport() {
+    assert_eq!(lifesupport(&generate(EXAMPLE).unwrap()), "230");
+    assert_eq!(
+        lifesupport(&DiagnosticReport::new(4, vec![]).unwrap()),
+        "no readings"
+    );
+}
+
+// The same answers for readings kept as strings
+pub fn battery_of_strings(lines: &[String]) -> Result<String, ParseError> {
+    DiagnosticReport::try_from(lines).map(|report| battery(&report))
+}
+
+pub fn lifesupport_of_strings(lines: &[String]) -> Result<String, ParseError> {
+    DiagnosticReport::try_from(lines).map(|report| lifesupport(&report))
+}
+
+#[test]
+fn test_strings() {
+    let lines: Vec<_> = EXAMPLE.lines().map(String::from).collect();
+    assert_eq!(battery_of_strings(&lines).unwrap(), "198");
+    assert_eq!(lifesupport_of_strings(&lines).unwrap(), "230");
+    assert_eq!(
+        battery_of_strings(&[String::from("01"), String::from("2")]).err(),
+        Some(ParseError::new(3, 2, 1, "0 or 1"))
+    );
+}
+
+// Readings of 12 bits, all different so the ratings narrow down to just one
+fn random_input(rng: &mut Random, size: usize) -> String {
+    sample(rng, 1 << 12, size.clamp(1, 1 << 12))
+        .into_iter()
+        .map(|n| format!("{:012b}", n))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = DiagnosticReport;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const RANDOM_SIZE: usize = 1000;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        battery(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        lifesupport(input)
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    // Part 2 whittles down the readings, so needs them all at once
+    fn stream(reader: &mut dyn BufRead, part: u32) -> Option<Result<String, StreamError>> {
+        (part == 1).then(|| stream_battery(reader))
+    }
+}
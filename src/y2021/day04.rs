@@ -0,0 +1,269 @@
+use crate::error::{number, ParseError};
+use crate::random::Random;
+use crate::solver::Solver;
+use crate::trace::trace;
+use rand::seq::{index::sample, SliceRandom};
+
+#[aoc_generator(day4)]
+fn generate(input: &str) -> Result<Game, ParseError> {
+    Game::try_from(input.lines().map(|s| s.to_string()).collect::<Vec<_>>())
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Value {
+    Matched(u32),
+    Unmatched(u32),
+}
+
+#[derive(Default, Clone)]
+struct Board {
+    rows: Vec<Vec<Value>>,
+}
+
+impl Board {
+    fn mark(&mut self, num: u32) {
+        for r in 0..self.rows.len() {
+            for c in 0..self.rows[r].len() {
+                if let Value::Unmatched(v) = self.rows[r][c] {
+                    if v == num {
+                        self.rows[r][c] = Value::Matched(num);
+                    }
+                }
+            }
+        }
+    }
+
+    fn row_matched(&self) -> bool {
+        for row in &self.rows {
+            if row.iter().all(|x| matches!(x, Value::Matched(_))) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn column_matched(&self) -> bool {
+        let columns = self.rows[0].len();
+        for c in 0..columns {
+            if self
+                .rows
+                .iter()
+                .map(|row| row[c])
+                .all(|x| matches!(x, Value::Matched(_)))
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn winning(&self) -> bool {
+        self.row_matched() || self.column_matched()
+    }
+
+    fn score(&self) -> u32 {
+        self.rows
+            .iter()
+            .flatten()
+            .map(|&v| match v {
+                Value::Unmatched(v) => v,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+
+impl TryFrom<Vec<String>> for Board {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, Self::Error> {
+        let mut board = Board::default();
+        for (n, l) in lines.iter().enumerate() {
+            board.rows.push(
+                l.split_whitespace()
+                    .map(|x| number(4, l, x).map(Value::Unmatched))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| e.on_line(n + 1))?,
+            );
+        }
+        Ok(board)
+    }
+}
+
+#[test]
+fn test_board() {
+    let mut board = Board::try_from(vec!["1 2 3".to_string()]).unwrap();
+    assert_eq!(board.rows.len(), 1);
+    assert_eq!(board.rows[0][0], Value::Unmatched(1));
+    assert_eq!(board.rows[0][1], Value::Unmatched(2));
+    assert_eq!(board.rows[0][2], Value::Unmatched(3));
+    assert_eq!(board.winning(), false);
+
+    board.mark(2);
+    assert_eq!(board.rows[0][0], Value::Unmatched(1));
+    assert_eq!(board.rows[0][1], Value::Matched(2));
+    assert_eq!(board.rows[0][2], Value::Unmatched(3));
+    assert_eq!(board.winning(), true);
+    assert_eq!(board.score(), 4);
+}
+
+#[derive(Clone)]
+pub struct Game {
+    numbers: Vec<u32>,
+    boards: Vec<Board>,
+}
+use itertools::Itertools;
+
+impl TryFrom<Vec<String>> for Game {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, Self::Error> {
+        let mut line = lines.iter().enumerate();
+        let first = match line.next() {
+            Some((_, first)) => first,
+            None => return Err(ParseError::new(4, 1, 1, "the numbers drawn")),
+        };
+        let numbers = first
+            .split(',')
+            .map(|x| number(4, first, x))
+            .collect::<Result<_, _>>()?;
+
+        let mut boards = vec![];
+        for (key, group) in &line.group_by(|(_, s)| !s.is_empty()) {
+            if key {
+                let group = group.collect::<Vec<_>>();
+                let start = group[0].0;
+                boards.push(
+                    Board::try_from(group.into_iter().map(|(_, x)| x.to_string()).collect_vec())
+                        .map_err(|e| e.on_line(start + 1))?,
+                )
+            }
+        }
+
+        Ok(Game { numbers, boards })
+    }
+}
+
+#[test]
+fn test_game_errors() {
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(4, 1, 1, "the numbers drawn"))
+    );
+    assert_eq!(
+        generate("1,2,x").err(),
+        Some(ParseError::new(4, 1, 5, "a number"))
+    );
+    assert_eq!(
+        generate("1,2,3\n\n 1  2\n 3  4\n\n 5  6\n 7 B8").err(),
+        Some(ParseError::new(4, 7, 4, "a number"))
+    );
+}
+
+use std::collections::HashSet;
+
+impl Game {
+    fn winning_score(&mut self) -> u32 {
+        for number in &self.numbers {
+            trace!("draw", number = number);
+            for (b, board) in self.boards.iter_mut().enumerate() {
+                board.mark(*number);
+                if board.winning() {
+                    trace!("win", board = b, score = board.score());
+                    return *number * board.score();
+                }
+            }
+        }
+        0
+    }
+
+    fn losing_score(&mut self) -> u32 {
+        let mut winners = HashSet::new();
+        let count = self.boards.len();
+        for number in &self.numbers {
+            trace!("draw", number = number);
+            for (b, board) in &mut self.boards.iter_mut().enumerate() {
+                board.mark(*number);
+                if board.winning() {
+                    if winners.insert(b) {
+                        trace!("win", board = b, score = board.score());
+                    }
+                    if winners.len() == count {
+                        return *number * board.score();
+                    }
+                }
+            }
+        }
+        0
+    }
+}
+
+#[test]
+fn test_bingo() {
+    let example = include_str!("day4_example.txt");
+    let mut game = generate(example).unwrap();
+
+    assert_eq!(game.boards.len(), 3);
+    assert_eq!(game.boards[0].rows[0][0], Value::Unmatched(22));
+    assert_eq!(game.boards[2].rows[4][4], Value::Unmatched(7));
+    assert_eq!(game.winning_score(), 4512);
+    assert_eq!(game.losing_score(), 1924);
+
+    let (score, events) = crate::trace::collect(|| generate(example).unwrap().winning_score());
+    assert_eq!(score, 4512);
+    assert_eq!(events.len(), 13);
+    assert_eq!(events[0].to_string(), "draw number=7");
+    assert_eq!(events[12].to_string(), "win board=2 score=188");
+}
+
+#[aoc(day4, part1)]
+fn winner(bingo: &Game) -> u32 {
+    let mut g = (*bingo).clone();
+    g.winning_score()
+}
+
+#[aoc(day4, part2)]
+fn loser(bingo: &Game) -> u32 {
+    let mut g = (*bingo).clone();
+    g.losing_score()
+}
+
+// `size` boards from the numbers 0 to 99, every one of them drawn, so every
+// board wins in the end
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut draws = (0..100).collect_vec();
+    draws.shuffle(rng);
+    let mut input = draws.iter().join(",");
+    for _ in 0..size.max(1) {
+        input.push('\n');
+        for row in sample(rng, 100, 25).into_vec().chunks(5) {
+            input.push('\n');
+            input.push_str(&row.iter().map(|n| format!("{:>2}", n)).join(" "));
+        }
+    }
+    input
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Game;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day4_example.txt"));
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        winner(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        loser(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
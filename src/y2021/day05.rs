@@ -0,0 +1,277 @@
+use crate::error::{number, parse_lines, ParseError};
+use crate::geom::{Bounds, Vec2};
+use crate::random::{lines, Random};
+use crate::render::{heat, plot, Picture, Rgb};
+use crate::solver::Solver;
+use crate::trace::{self, trace};
+use rand::Rng;
+
+#[aoc_generator(day5)]
+fn generate(input: &str) -> Result<Vec<Edge>, ParseError> {
+    parse_lines(input, |l| Edge::try_from(l))
+}
+
+// Parse the point `s`, reporting errors at their position on `line`
+fn parse_point(line: &str, s: &str) -> Result<Vec2, ParseError> {
+    if let Some((x, y)) = s.split_once(',') {
+        Ok(Vec2::new(
+            number::<u32>(5, line, x)?.into(),
+            number::<u32>(5, line, y)?.into(),
+        ))
+    } else {
+        Err(ParseError::at(5, line, s, "a point x,y"))
+    }
+}
+
+#[test]
+fn test_point() {
+    let point = parse_point("1,2", "1,2").unwrap();
+    assert_eq!(point.x, 1);
+    assert_eq!(point.y, 2);
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Edge {
+    start: Vec2,
+    end: Vec2,
+}
+
+impl TryFrom<&str> for Edge {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if let Some((start, end)) = s.split_once(" -> ") {
+            Ok(Edge {
+                start: parse_point(s, start)?,
+                end: parse_point(s, end)?,
+            })
+        } else {
+            Err(ParseError::at(5, s, s, "a line x1,y1 -> x2,y2"))
+        }
+    }
+}
+
+impl Edge {
+    // Every point from start to end, which is only ever a straight line or
+    // a 45 degree diagonal
+    fn walk(self) -> Vec<Vec2> {
+        let step = (self.end - self.start).signum();
+        (0..=self.start.chebyshev(self.end))
+            .map(|n| self.start + step * n)
+            .collect()
+    }
+
+    fn points(self) -> Vec<Vec2> {
+        if self.start.x == self.end.x || self.start.y == self.end.y {
+            self.walk()
+        } else {
+            vec![]
+        }
+    }
+
+    fn diagonal_points(self) -> Vec<Vec2> {
+        self.walk()
+    }
+}
+
+#[test]
+fn test_edge() {
+    let edge = Edge::try_from("1,2 -> 1,4").unwrap();
+    assert_eq!(edge.start.x, 1);
+    assert_eq!(edge.start.y, 2);
+    assert_eq!(edge.end.x, 1);
+    assert_eq!(edge.end.y, 4);
+    assert_eq!(
+        edge.points(),
+        [(1, 2), (1, 3), (1, 4)]
+            .iter()
+            .map(|x| Vec2::from(*x))
+            .collect::<Vec<_>>(),
+    );
+
+    let edge = Edge::try_from("3,4 -> 2,4").unwrap();
+    assert_eq!(
+        edge.points(),
+        [(3, 4), (2, 4)]
+            .iter()
+            .map(|x| Vec2::from(*x))
+            .collect::<Vec<_>>(),
+    );
+
+    let edge = Edge::try_from("1,1 -> 3,3").unwrap();
+    assert_eq!(
+        edge.diagonal_points(),
+        [(1, 1), (2, 2), (3, 3)]
+            .iter()
+            .map(|x| Vec2::from(*x))
+            .collect::<Vec<_>>(),
+    );
+
+    let edge = Edge::try_from("9,7 -> 7,9").unwrap();
+    assert_eq!(
+        edge.diagonal_points(),
+        [(9, 7), (8, 8), (7, 9)]
+            .iter()
+            .map(|x| Vec2::from(*x))
+            .collect::<Vec<_>>(),
+    );
+}
+use std::collections::HashMap;
+
+// Traces the vent counts a row at a time
+fn draw_points(points: &HashMap<Vec2, i32>) {
+    let bounds = match Bounds::of(points.keys().copied()) {
+        Some(bounds) if trace::enabled() => bounds,
+        _ => return,
+    };
+    for y in bounds.min.y..=bounds.max.y {
+        let counts = (bounds.min.x..=bounds.max.x)
+            .map(|x| {
+                points
+                    .get(&Vec2::new(x, y))
+                    .copied()
+                    .unwrap_or(0)
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        trace!("row", y = y, counts = counts);
+    }
+}
+
+// How many vents lie over each point
+fn vent_counts(edges: &[Edge], points: fn(Edge) -> Vec<Vec2>) -> HashMap<Vec2, i32> {
+    edges
+        .iter()
+        .copied()
+        .flat_map(points)
+        .fold(HashMap::new(), |mut acc, p| {
+            acc.entry(p).and_modify(|c| *c += 1).or_insert(1);
+            acc
+        })
+}
+
+fn highway_to_the_danger_zones(edges: &[Edge], points: fn(Edge) -> Vec<Vec2>) -> u32 {
+    let points = vent_counts(edges, points);
+
+    draw_points(&points);
+
+    points.values().filter(|p| **p >= 2).count() as u32
+}
+
+// The overlaps as a heatmap, hotter where more vents cross
+fn heatmap(edges: &[Edge], points: fn(Edge) -> Vec<Vec2>) -> Picture {
+    let counts = vent_counts(edges, points);
+    let max = counts.values().copied().max().unwrap_or(0) as u64;
+    // Keep the origin in frame, so both parts line up
+    let origin = (Vec2::ZERO, Rgb::BLACK);
+    plot(
+        std::iter::once(origin).chain(counts.into_iter().map(|(p, c)| (p, heat(c as u64, max)))),
+        Rgb::BLACK,
+    )
+}
+
+#[aoc(day5, part1)]
+fn danger_zones(edges: &[Edge]) -> u32 {
+    highway_to_the_danger_zones(edges, |e| e.points())
+}
+
+#[aoc(day5, part2)]
+fn diagonal_danger_zones(edges: &[Edge]) -> u32 {
+    highway_to_the_danger_zones(edges, |e| e.diagonal_points())
+}
+
+#[test]
+fn test_danger_zones() {
+    let example = r#"
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#;
+    let edges = generate(example.trim()).unwrap();
+
+    assert_eq!(danger_zones(&edges), 5);
+    assert_eq!(diagonal_danger_zones(&edges), 12);
+
+    let picture = heatmap(&edges, |e| e.diagonal_points());
+    assert_eq!((picture.width(), picture.height()), (10, 10));
+    assert_eq!(picture[(0, 0)], heat(1, 3));
+    assert_eq!(picture[(2, 2)], heat(2, 3));
+    assert_eq!(picture[(1, 0)], Rgb::BLACK);
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("0,9 -> 5,9\n8,0 -> 0,8\n9,4 - 3,4").err(),
+        Some(ParseError::new(5, 3, 1, "a line x1,y1 -> x2,y2"))
+    );
+    assert_eq!(
+        generate("0,9 -> 5,9\n8,0 -> 0;8").err(),
+        Some(ParseError::new(5, 2, 8, "a point x,y"))
+    );
+    assert_eq!(
+        generate("0,9 -> 5,-9").err(),
+        Some(ParseError::new(5, 1, 10, "a number"))
+    );
+}
+
+// Lines across a 1000 square, straight or at 45 degrees
+fn random_input(rng: &mut Random, size: usize) -> String {
+    lines(rng, size, |rng| {
+        let start = Vec2::new(rng.gen_range(0..1000), rng.gen_range(0..1000));
+        let step = loop {
+            let step = Vec2::new(rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+            if step != Vec2::ZERO {
+                break step;
+            }
+        };
+        let room = |at: i64, dir: i64| match dir {
+            1 => 999 - at,
+            -1 => at,
+            _ => i64::MAX,
+        };
+        let length = rng
+            .gen_range(1..500)
+            .min(room(start.x, step.x))
+            .min(room(start.y, step.y));
+        let end = start + step * length;
+        format!("{},{} -> {},{}", start.x, start.y, end.x, end.y)
+    })
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Vec<Edge>;
+    const RANDOM_SIZE: usize = 500;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        danger_zones(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        diagonal_danger_zones(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn render(input: &Self::Input, part: u32) -> Option<Picture> {
+        match part {
+            1 => Some(heatmap(input, |e| e.points())),
+            _ => Some(heatmap(input, |e| e.diagonal_points())),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::error::{number, ParseError};
+use crate::grid::Grid;
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::Random;
+use crate::render::{palette, Picture, Rgb};
+use crate::solver::Solver;
+use rand::Rng;
+
+type Count = u64;
+type Population = [Count; 9];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day06Params {
+    pub part1_days: u32,
+    pub part2_days: u32,
+}
+
+impl Default for Day06Params {
+    fn default() -> Self {
+        Day06Params {
+            part1_days: 80,
+            part2_days: 256,
+        }
+    }
+}
+
+// The fish multiply too fast for a Count after some 500 days, sooner the
+// more there are to begin with
+fn fits(pop: Population, days: u32) -> bool {
+    let total = |pop: &Population| {
+        pop.iter()
+            .try_fold(0 as Count, |sum, &n| sum.checked_add(n))
+    };
+    let mut last = pop;
+    for _ in 0..days {
+        // No age can outgrow the whole lot, so this step is safe
+        if total(&last).is_none() {
+            return false;
+        }
+        last = step(last);
+    }
+    total(&last).is_some()
+}
+
+// How long one newborn fish can breed for and still be counted, as a first
+// check before there's a whole school to try
+fn days(name: &str, value: &str) -> Result<u32, ParamError> {
+    let days = params::value(name, value)?;
+    if !fits([1, 0, 0, 0, 0, 0, 0, 0, 0], days) {
+        return Err(params::invalid(name, value));
+    }
+    Ok(days)
+}
+
+impl Params for Day06Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part1_days" => self.part1_days = days(name, value)?,
+            "part2_days" => self.part2_days = days(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[aoc_generator(day6)]
+fn generate(input: &str) -> Result<Population, ParseError> {
+    let line = input.trim();
+    let mut pop = [0; 9];
+    for s in line.split(',') {
+        let age: usize = number(6, line, s)?;
+        if age >= pop.len() {
+            return Err(ParseError::at(6, line, s, "an age from 0 to 8"));
+        }
+        pop[age] += 1;
+    }
+
+    Ok(pop)
+}
+
+#[test]
+fn test_generate() {
+    assert_eq!(generate("3,4,3,1,2"), Ok([0, 1, 1, 2, 1, 0, 0, 0, 0]));
+    assert_eq!(
+        generate("3,4,9,1,2"),
+        Err(ParseError::new(6, 1, 5, "an age from 0 to 8"))
+    );
+    assert_eq!(generate("3,4,"), Err(ParseError::new(6, 1, 5, "a number")));
+}
+
+fn step(pop: Population) -> Population {
+    let mut next = pop;
+    next.rotate_left(1);
+    next[6] += pop[0];
+    next
+}
+
+#[test]
+fn test_step() {
+    assert_eq!(
+        step([1, 0, 0, 0, 0, 0, 0, 0, 0]),
+        [0, 0, 0, 0, 0, 0, 1, 0, 1]
+    );
+}
+
+fn run(pop: Population, steps: u32) -> Count {
+    let mut last = pop;
+    for _ in 0..steps {
+        last = step(last)
+    }
+    last.iter().sum()
+}
+
+#[test]
+fn test_run() {
+    assert_eq!(run([1, 0, 0, 0, 0, 0, 0, 0, 0], 0), 1);
+    assert_eq!(run([1, 0, 0, 0, 0, 0, 0, 0, 0], 1), 2);
+}
+
+#[test]
+fn test_example() {
+    assert_eq!(run(generate("3,4,3,1,2").unwrap(), 80), 5934);
+    assert_eq!(run(generate("3,4,3,1,2").unwrap(), 256), 26984457539);
+}
+
+// A bar per age, its height the share of the biggest group that day, since
+// the counts soon grow far beyond anything fixed would fit
+fn chart(pop: &Population) -> Picture {
+    const BAR: usize = 4;
+    const HEIGHT: usize = 64;
+    let mut picture = Grid::new(pop.len() * (BAR + 1), HEIGHT, Rgb::BLACK);
+    let max = pop.iter().copied().max().unwrap_or(0).max(1);
+    for (age, &count) in pop.iter().enumerate() {
+        let height = (count * HEIGHT as Count / max) as usize;
+        for (x, y) in itertools::iproduct!(0..BAR, HEIGHT - height..HEIGHT) {
+            picture[(age * (BAR + 1) + x, y)] = palette(age);
+        }
+    }
+    picture
+}
+
+fn frames(pop: Population, steps: u32) -> Vec<Picture> {
+    let mut last = pop;
+    let mut frames = vec![chart(&last)];
+    for _ in 0..steps {
+        last = step(last);
+        frames.push(chart(&last));
+    }
+    frames
+}
+
+#[test]
+fn test_frames() {
+    let frames = frames([0, 2, 1, 0, 0, 0, 0, 0, 0], 1);
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0][(5, 0)], palette(1));
+    assert_eq!(frames[0][(10, 31)], Rgb::BLACK);
+    assert_eq!(frames[0][(10, 32)], palette(2));
+    assert_eq!(frames[1][(0, 0)], palette(0));
+}
+
+#[aoc(day6, part1)]
+fn eighty_days(pop: &Population) -> Count {
+    run(*pop, Day06Params::default().part1_days)
+}
+
+#[aoc(day6, part2)]
+fn more_days(pop: &Population) -> Count {
+    run(*pop, Day06Params::default().part2_days)
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = Configured<Population, Day06Params>;
+    const RANDOM_SIZE: usize = 300;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        run(input.input, input.params.part1_days).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        run(input.input, input.params.part2_days).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        let mut params = input.params.clone();
+        params.set(name, value)?;
+        if !fits(input.input, params.part1_days.max(params.part2_days)) {
+            return Err(params::invalid(name, value));
+        }
+        input.params = params;
+        Ok(())
+    }
+
+    fn animate(input: &Self::Input, part: u32) -> Option<Vec<Picture>> {
+        let days = match part {
+            1 => input.params.part1_days,
+            _ => input.params.part2_days,
+        };
+        Some(frames(input.input, days))
+    }
+}
+
+#[test]
+fn test_params() {
+    let mut input = Day06::parse("3,4,3,1,2").unwrap();
+    assert_eq!(Day06::part1(&input), "5934");
+    Day06::configure(&mut input, "part1_days", "18").unwrap();
+    assert_eq!(Day06::part1(&input), "26");
+    assert_eq!(Day06::animate(&input, 1).unwrap().len(), 19);
+    assert!(Day06::configure(&mut input, "part1_days", "many").is_err());
+    assert!(Day06::configure(&mut input, "days", "18").is_err());
+    assert_eq!(
+        Day06::configure(&mut input, "part2_days", "1000")
+            .unwrap_err()
+            .to_string(),
+        "part2_days can't be 1000"
+    );
+    // Few enough days for one fish, but not for five
+    assert!(fits([1, 0, 0, 0, 0, 0, 0, 0, 0], 500));
+    assert!(Day06::configure(&mut input, "part2_days", "500").is_err());
+    assert_eq!(Day06::part2(&input), "26984457539");
+}
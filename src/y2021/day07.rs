@@ -1,3 +1,8 @@
+use crate::error::{number, ParseError};
+use crate::random::Random;
+use crate::solver::Solver;
+use rand::Rng;
+
 type Pos = i32;
 
 fn cost_move(crabs: &[Pos], position: Pos, fuel: fn(Pos) -> Pos) -> Pos {
@@ -31,24 +36,22 @@ fn cheapest(crabs: &[Pos], cost: fn(&[Pos], Pos) -> Pos) -> Pos {
     let &start = crabs.iter().min().unwrap();
     let &end = crabs.iter().max().unwrap();
 
-    (start..end)
-        .into_iter()
-        .map(|p| cost(crabs, p))
-        .min()
-        .unwrap()
+    (start..=end).map(|p| cost(crabs, p)).min().unwrap()
 }
 
 #[test]
 fn test_cheapest() {
     let example = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
     assert_eq!(cheapest(&example, cost), 37);
+    assert_eq!(cheapest(&[9], cost), 0);
+    assert_eq!(cheapest(&[3, 3, 3], real_cost), 0);
 }
 
 use memoize::memoize;
 
 #[memoize]
 fn fuel_cost(distance: Pos) -> Pos {
-    (1..=distance).into_iter().sum()
+    (1..=distance).sum()
 }
 
 #[test]
@@ -70,11 +73,17 @@ fn test_real_cost() {
 }
 
 #[aoc_generator(day7)]
-fn generate(input: &str) -> Vec<Pos> {
-    input
-        .split(',')
-        .map(|s| s.parse::<Pos>().unwrap())
-        .collect::<Vec<_>>()
+fn generate(input: &str) -> Result<Vec<Pos>, ParseError> {
+    input.split(',').map(|s| number(7, input, s)).collect()
+}
+
+#[test]
+fn test_generate() {
+    assert_eq!(generate("16,1,2"), Ok(vec![16, 1, 2]));
+    assert_eq!(
+        generate("16,1,,2"),
+        Err(ParseError::new(7, 1, 6, "a number"))
+    );
 }
 
 #[aoc(day7, part1)]
@@ -86,3 +95,37 @@ fn part1(input: &[Pos]) -> Pos {
 fn part2(input: &[Pos]) -> Pos {
     cheapest(input, real_cost)
 }
+
+// Crabs bunched up towards the start, like the real ones
+fn random_input(rng: &mut Random, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let p: Pos = rng.gen_range(0..2000);
+            (p * p / 2000).to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = Vec<Pos>;
+    const RANDOM_SIZE: usize = 1000;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
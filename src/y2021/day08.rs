@@ -0,0 +1,191 @@
+use crate::error::{parse_lines, ParseError};
+use crate::random::{lines, Random};
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+
+#[aoc_generator(day8)]
+fn generate(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_lines(input, |line| {
+        let (patterns, outputs) = line
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::at(8, line, "", "ten patterns | four outputs"))?;
+        for (part, count) in [(patterns, 10), (outputs, 4)] {
+            let words = part.split(' ').collect::<Vec<_>>();
+            for word in &words {
+                if word.is_empty() || word.len() > 7 || !word.chars().all(|c| "abcdefg".contains(c))
+                {
+                    return Err(ParseError::at(8, line, word, "segments a to g"));
+                }
+            }
+            if words.len() != count {
+                return Err(ParseError::at(8, line, part, format!("{} words", count)));
+            }
+        }
+        Ok(line.to_string())
+    })
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("ab | cd").err(),
+        Some(ParseError::new(8, 1, 1, "10 words"))
+    );
+    assert_eq!(
+        generate("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgh gcbe").err(),
+        Some(ParseError::new(8, 1, 76, "segments a to g"))
+    );
+    assert_eq!(
+        generate("be cfbegad cbdgef").err(),
+        Some(ParseError::new(8, 1, 18, "ten patterns | four outputs"))
+    );
+}
+
+type Digit = u8;
+
+#[allow(dead_code)] // the digits document the candidates
+enum Output {
+    Is(Digit),
+    Maybe(Vec<Digit>),
+}
+
+fn output_is(s: &str) -> Output {
+    match s.len() {
+        2 => Output::Is(1),
+        3 => Output::Is(7),
+        4 => Output::Is(4),
+        5 => Output::Maybe(vec![2, 3, 5]),
+        6 => Output::Maybe(vec![0, 6, 9]),
+        7 => Output::Is(8),
+        _ => unreachable!(),
+    }
+}
+
+#[aoc(day8, part1)]
+fn known_outputs(input: &[String]) -> usize {
+    input
+        .iter()
+        .flat_map(|l| match l.split_once('|') {
+            Some((_, out)) => out.trim().split(' ').collect(),
+            None => vec![],
+        })
+        .map(output_is)
+        .filter(|x| matches!(x, Output::Is(_)))
+        .count()
+}
+
+#[test]
+fn test_known_outputs() {
+    let example = r#"
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+"#;
+
+    assert_eq!(known_outputs(&generate(example.trim()).unwrap()), 26);
+}
+
+fn decode_segments(s: &str) -> u32 {
+    let one = s.split(' ').find(|s| s.len() == 2).unwrap();
+    let four = s.split(' ').find(|s| s.len() == 4).unwrap();
+    let digits = s.split_once('|').unwrap().1.trim();
+
+    digits
+        .split(' ')
+        .map(|s| match s.len() {
+            2 => 1,
+            4 => 4,
+            3 => 7,
+            7 => 8,
+            len => {
+                let ones = one.chars().filter(|&c| s.contains(c)).count();
+                let fours = four.chars().filter(|&c| s.contains(c)).count();
+                match (len, ones, fours) {
+                    (6, _, 3) => 0,
+                    (5, 1, 2) => 2,
+                    (5, 2, 3) => 3,
+                    (5, 1, 3) => 5,
+                    (6, 1, _) => 6,
+                    (6, _, 4) => 9,
+                    _ => unreachable!(),
+                }
+            }
+        })
+        .fold(0, |acc, x| acc * 10 + x)
+}
+
+#[test]
+fn test_decode_segments() {
+    let example =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    assert_eq!(decode_segments(example), 5353);
+}
+
+#[aoc(day8, part2)]
+fn decode_all_segments(input: &[String]) -> u32 {
+    input.iter().map(|s| decode_segments(s)).sum()
+}
+
+// The segments lit for each digit, on a display wired up properly
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+// Every display wired up its own way, the segments of each pattern shuffled
+fn random_input(rng: &mut Random, size: usize) -> String {
+    lines(rng, size, |rng| {
+        let mut wiring = "abcdefg".chars().collect_vec();
+        wiring.shuffle(rng);
+        let wire = |rng: &mut Random, digit: &str| {
+            let mut segments = digit
+                .bytes()
+                .map(|b| wiring[(b - b'a') as usize])
+                .collect_vec();
+            segments.shuffle(rng);
+            segments.into_iter().collect::<String>()
+        };
+
+        let mut patterns = DIGITS.to_vec();
+        patterns.shuffle(rng);
+        let patterns = patterns.iter().map(|d| wire(rng, d)).join(" ");
+        let outputs = (0..4)
+            .map(|_| {
+                let digit = DIGITS[rng.gen_range(0..10)];
+                wire(rng, digit)
+            })
+            .join(" ");
+        format!("{} | {}", patterns, outputs)
+    })
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Vec<String>;
+    const RANDOM_SIZE: usize = 200;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        known_outputs(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        decode_all_segments(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
@@ -0,0 +1,163 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::random::{digit, grid, Random};
+use crate::render::{palette, Picture, Rgb};
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::Rng;
+
+#[derive(Debug)]
+pub struct Heightmap {
+    map: Grid<u8>,
+}
+
+impl TryFrom<&str> for Heightmap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Heightmap {
+            map: Grid::parse_digits(9, input)?,
+        })
+    }
+}
+
+impl Heightmap {
+    fn low_points(&self) -> Vec<u8> {
+        self.map
+            .indexed_iter()
+            .filter(|&(at, &val)| self.map.neighbours4(at).all(|n| val < self.map[n]))
+            .map(|(_, &val)| val)
+            .collect()
+    }
+
+    // Which basin each point drains into, numbered in reading order.  The
+    // 9s are ridges between basins, and belong to none.
+    fn basins(&self) -> Grid<Option<usize>> {
+        fn fill(m: &Heightmap, basins: &mut Grid<Option<usize>>, at: (usize, usize), id: usize) {
+            basins[at] = Some(id);
+            for n in m.map.neighbours4(at) {
+                if m.map[n] != 9 && basins[n].is_none() {
+                    fill(m, basins, n, id);
+                }
+            }
+        }
+
+        let mut basins = self.map.map(|_| None);
+        let mut id = 0;
+        for at in self.map.positions() {
+            if self.map[at] != 9 && basins[at].is_none() {
+                fill(self, &mut basins, at, id);
+                id += 1;
+            }
+        }
+        basins
+    }
+
+    fn basin_sizes(&self) -> Vec<u32> {
+        let basins = self.basins();
+        let mut sizes = vec![0; basins.iter().flatten().max().map_or(0, |&id| id + 1)];
+        for &id in basins.iter().flatten() {
+            sizes[id] += 1;
+        }
+        sizes
+    }
+
+    // Each basin its own colour, the ridges black
+    fn picture(&self) -> Picture {
+        self.basins().map(|basin| match basin {
+            Some(id) => palette(*id),
+            None => Rgb::BLACK,
+        })
+    }
+}
+
+#[test]
+fn test_low_points() {
+    let example = r#"
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+"#;
+
+    let map = generate(example.trim()).unwrap();
+    assert_eq!(map.low_points(), [1, 0, 5, 5]);
+    assert_eq!(risk_level(&map), 15);
+    assert_eq!(map.basin_sizes(), [3, 9, 14, 9]);
+
+    let picture = map.picture();
+    assert_eq!(picture[(0, 0)], palette(0));
+    assert_eq!(picture[(2, 0)], Rgb::BLACK);
+    assert_eq!(picture[(9, 0)], palette(1));
+}
+
+#[aoc_generator(day9)]
+fn generate(input: &str) -> Result<Heightmap, ParseError> {
+    Heightmap::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("2199\n3987\n985").err(),
+        Some(ParseError::new(9, 3, 4, "4 columns"))
+    );
+    assert_eq!(
+        generate("2199\n39.7").err(),
+        Some(ParseError::new(9, 2, 3, "a digit"))
+    );
+}
+
+#[aoc(day9, part1)]
+fn risk_level(heightmap: &Heightmap) -> u32 {
+    heightmap.low_points().iter().map(|&x| x as u32 + 1).sum()
+}
+
+#[aoc(day9, part2)]
+fn biggest_basins(map: &Heightmap) -> u32 {
+    map.basin_sizes()
+        .into_iter()
+        .sorted()
+        .rev()
+        .take(3)
+        .product()
+}
+
+// Plenty of 9s, to wall off the basins
+fn random_input(rng: &mut Random, size: usize) -> String {
+    grid(rng, size, size, |rng| {
+        if rng.gen_bool(0.3) {
+            '9'
+        } else {
+            digit(rng, 0..=8)
+        }
+    })
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Heightmap;
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        risk_level(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        biggest_basins(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn render(input: &Self::Input, _part: u32) -> Option<Picture> {
+        Some(input.picture())
+    }
+}
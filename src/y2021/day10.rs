@@ -0,0 +1,281 @@
+use crate::error::{parse_lines, stream_lines, ParseError, StreamError};
+use crate::random::{lines, Random};
+use crate::solver::Solver;
+use itertools::{process_results, Itertools};
+use rand::Rng;
+use std::io::BufRead;
+
+#[derive(Debug, PartialEq)]
+enum Validation {
+    Ok,
+    Invalid(char),
+    Incomplete(String),
+}
+
+fn validate(syntax: &str) -> Validation {
+    let mut stack = vec![];
+    for c in syntax.chars() {
+        match c {
+            '(' => stack.push(')'),
+            '{' => stack.push('}'),
+            '<' => stack.push('>'),
+            '[' => stack.push(']'),
+            ')' | '}' | '>' | ']' => {
+                if let Some(expected) = stack.pop() {
+                    if c != expected {
+                        return Validation::Invalid(c);
+                    }
+                } else {
+                    return Validation::Invalid(c);
+                }
+            }
+            _ => return Validation::Invalid(c),
+        }
+    }
+    if !stack.is_empty() {
+        return Validation::Incomplete(String::from_iter(stack.into_iter().rev()));
+    }
+    Validation::Ok
+}
+
+#[test]
+fn test_validate() {
+    assert_eq!(validate("()"), Validation::Ok);
+    assert_eq!(validate("<>"), Validation::Ok);
+    assert_eq!(validate("{}"), Validation::Ok);
+    assert_eq!(validate("[]"), Validation::Ok);
+    assert_eq!(validate("([][])"), Validation::Ok);
+    assert_eq!(validate("<<[]>>"), Validation::Ok);
+
+    assert_eq!(validate("}"), Validation::Invalid('}'));
+
+    assert_eq!(validate("["), Validation::Incomplete(String::from("]")));
+
+    assert_eq!(validate("[("), Validation::Incomplete(String::from(")]")));
+
+    assert_eq!(
+        validate("{([(<{}[<>[]}>{[]{[(<()>"),
+        Validation::Invalid('}')
+    );
+    assert_eq!(validate("[[<[([]))<([[{}[[()]]]"), Validation::Invalid(')'));
+}
+
+fn score_invalid(s: &str) -> u32 {
+    match validate(s) {
+        Validation::Invalid(c) => match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_score_invalid() {
+    assert_eq!(score_invalid(">"), 25137);
+    assert_eq!(score_invalid("<>"), 0);
+}
+
+fn check_brackets(line: &str) -> Result<&str, ParseError> {
+    match line.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+        Some((i, _)) => Err(ParseError::at(10, line, &line[i..], "a bracket")),
+        None => Ok(line),
+    }
+}
+
+fn no_lines() -> ParseError {
+    ParseError::new(10, 1, 1, "a bracket")
+}
+
+#[aoc_generator(day10)]
+fn generate(input: &str) -> Result<Vec<String>, ParseError> {
+    if input.is_empty() {
+        return Err(no_lines());
+    }
+    parse_lines(input, |line| check_brackets(line).map(String::from))
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]} >{[]{[(<()>")
+            .err(),
+        Some(ParseError::new(10, 3, 14, "a bracket"))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(10, 1, 1, "a bracket"))
+    );
+}
+
+#[aoc(day10, part1)]
+fn syntax_score(lines: &[String]) -> u32 {
+    lines.iter().map(|s| score_invalid(s)).sum()
+}
+
+#[test]
+fn test_syntax_score() {
+    let example = r#"
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+"#;
+
+    assert_eq!(syntax_score(&generate(example.trim()).unwrap()), 26397);
+    assert_eq!(
+        Day10::stream(&mut example.trim_start().as_bytes(), 1).and_then(Result::ok),
+        Some(String::from("26397"))
+    );
+    assert_eq!(
+        Day10::stream(&mut "".as_bytes(), 1).map(|r| r.unwrap_err().to_string()),
+        Some(generate("").unwrap_err().to_string())
+    );
+}
+
+fn score_autocomplete(s: &str) -> u64 {
+    match validate(s) {
+        Validation::Incomplete(completion) => completion
+            .chars()
+            .map(|c| match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => unreachable!(),
+            })
+            .fold(0, |acc, v| acc * 5 + v),
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_score_autocomplete() {
+    assert_eq!(score_autocomplete("<{([{{}}[<[[[<>{}]]]>[]]"), 294);
+    assert_eq!(score_autocomplete("[(()[<>])]({[<{<<[]>>("), 5566);
+}
+
+// The middle score, or 0 when every line is corrupted and there's none
+#[aoc(day10, part2)]
+fn score_auto_many(lines: &[String]) -> u64 {
+    let scored = lines
+        .iter()
+        .map(|s| score_autocomplete(s))
+        .filter(|&x| x != 0)
+        .sorted()
+        .collect::<Vec<_>>();
+    scored.get(scored.len() / 2).copied().unwrap_or(0)
+}
+
+#[test]
+fn test_score_auto_many() {
+    let example = r#"
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+"#;
+
+    assert_eq!(score_auto_many(&generate(example.trim()).unwrap()), 288957);
+    assert_eq!(score_auto_many(&generate("()\n(]").unwrap()), 0);
+}
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// Chunks opening and closing at random, then either a wrong closing
+// character, or left with some still open.  No more than 20 are ever open at
+// once, so the autocomplete scores fit.
+fn random_line(rng: &mut Random, corrupted: bool) -> String {
+    let mut line = String::new();
+    let mut open = vec![];
+    for _ in 0..rng.gen_range(20..110) {
+        if open.is_empty() || (open.len() < 20 && rng.gen_bool(0.55)) {
+            let (opening, closing) = PAIRS[rng.gen_range(0..4)];
+            line.push(opening);
+            open.push(closing);
+        } else {
+            line.push(open.pop().unwrap());
+        }
+    }
+    if open.is_empty() {
+        let (opening, closing) = PAIRS[rng.gen_range(0..4)];
+        line.push(opening);
+        open.push(closing);
+    }
+    if corrupted {
+        let expected = open.pop().unwrap();
+        let wrong = PAIRS
+            .iter()
+            .map(|&(_, closing)| closing)
+            .filter(|&c| c != expected)
+            .collect::<Vec<_>>();
+        line.push(wrong[rng.gen_range(0..wrong.len())]);
+    }
+    line
+}
+
+// About half the lines corrupted, but always at least one incomplete
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut first = true;
+    lines(rng, size.max(1), |rng| {
+        let corrupted = !first && rng.gen_bool(0.5);
+        first = false;
+        random_line(rng, corrupted)
+    })
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<String>;
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        syntax_score(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        score_auto_many(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    // Part 2 wants the middle score, so needs every line's at once
+    fn stream(reader: &mut dyn BufRead, part: u32) -> Option<Result<String, StreamError>> {
+        if part != 1 {
+            return None;
+        }
+        let mut lines = 0;
+        let scores = stream_lines(reader, |line| {
+            lines += 1;
+            check_brackets(line).map(score_invalid)
+        });
+        Some(
+            match process_results(scores, |scores| scores.sum::<u32>()) {
+                Ok(_) if lines == 0 => Err(no_lines().into()),
+                total => total.map(|score| score.to_string()),
+            },
+        )
+    }
+}
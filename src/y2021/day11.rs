@@ -0,0 +1,229 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::{digit, grid, Random};
+use crate::render::{shade, Picture, Rgb};
+use crate::solver::Solver;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+pub struct State {
+    data: Grid<u8>,
+}
+
+impl TryFrom<&str> for State {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(State {
+            data: Grid::parse_digits(11, input)?,
+        })
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = r#"
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+"#;
+
+#[test]
+fn test_state_from() {
+    let state = State::try_from(EXAMPLE.trim()).unwrap();
+    assert_eq!(state.data[(0, 0)], 5);
+    assert_eq!(state.data[(1, 0)], 4);
+    assert_eq!((state.data.width(), state.data.height()), (10, 10));
+}
+
+impl State {
+    fn step(&mut self) -> usize {
+        // You get an energy, and you get an energy!
+        self.data.iter_mut().for_each(|v| *v += 1);
+
+        let mut seen = HashSet::<(usize, usize)>::new();
+        loop {
+            let flashed = HashSet::from_iter(
+                self.data
+                    .indexed_iter()
+                    .filter(|(_, &v)| v > 9)
+                    .map(|(point, _)| point),
+            );
+            if flashed != seen {
+                // new flashes to handle
+                for &at in flashed.difference(&seen) {
+                    // add energy to each neighbour
+                    for n in self.data.neighbours8(at).collect::<Vec<_>>() {
+                        self.data[n] += 1;
+                    }
+                }
+                seen = flashed;
+            } else {
+                // reset all flashed cells to 0
+                flashed.iter().for_each(|&at| self.data[at] = 0);
+                return flashed.len();
+            }
+        }
+    }
+}
+
+impl State {
+    // Those which just flashed light up, the rest glow with their energy
+    fn picture(&self) -> Picture {
+        self.data.map(|&v| match v {
+            0 => Rgb::WHITE,
+            v => shade(v as u64, 12),
+        })
+    }
+}
+
+#[test]
+fn test_state_step() {
+    let mut state = State::try_from("800\n000\n000").unwrap();
+    assert_eq!(state.step(), 0);
+    assert_eq!(state.data.to_string(), "911\n111\n111");
+
+    assert_eq!(state.step(), 1);
+    assert_eq!(state.data.to_string(), "032\n332\n222");
+    assert_eq!(state.picture()[(0, 0)], Rgb::WHITE);
+    assert_eq!(state.picture()[(1, 0)], shade(3, 12));
+
+    assert_eq!(state.step(), 0);
+    assert_eq!(state.data.to_string(), "143\n443\n333");
+}
+
+#[aoc_generator(day11)]
+fn generate(input: &str) -> Result<State, ParseError> {
+    State::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("5483\n2745\n526").err(),
+        Some(ParseError::new(11, 3, 4, "4 columns"))
+    );
+    assert_eq!(
+        generate("5483\n27x5").err(),
+        Some(ParseError::new(11, 2, 3, "a digit"))
+    );
+}
+
+// Part 1 counts flashes over this many steps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day11Params {
+    pub steps: usize,
+}
+
+impl Default for Day11Params {
+    fn default() -> Self {
+        Day11Params { steps: 100 }
+    }
+}
+
+impl Params for Day11Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "steps" => self.steps = params::value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[aoc(day11, part1)]
+fn count_100_flashes(state: &State) -> usize {
+    count_flashes(state, Day11Params::default().steps)
+}
+
+fn count_flashes(start: &State, steps: usize) -> usize {
+    let mut total = 0;
+    let mut state = start.clone();
+    for _ in 0..steps {
+        total += state.step();
+    }
+    total
+}
+
+#[test]
+fn test_count_flashes() {
+    let state = State::try_from(EXAMPLE.trim()).unwrap();
+    assert_eq!(count_flashes(&state, 10), 204);
+    assert_eq!(count_flashes(&state, 100), 1656);
+}
+
+#[aoc(day11, part2)]
+fn when_everyone_flashes(start: &State) -> usize {
+    let mut state = start.clone();
+    let everyone = state.data.len();
+    for step in 1.. {
+        if state.step() == everyone {
+            return step;
+        }
+    }
+    unreachable!()
+}
+
+#[test]
+fn test_when_everyone_flashes() {
+    assert_eq!(
+        when_everyone_flashes(&generate(EXAMPLE.trim()).unwrap()),
+        195
+    );
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    grid(rng, size, size, |rng| digit(rng, 0..=9))
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Configured<State, Day11Params>;
+    const RANDOM_SIZE: usize = 10;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        count_flashes(&input.input, input.params.steps).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        when_everyone_flashes(&input.input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+
+    fn render(input: &Self::Input, part: u32) -> Option<Picture> {
+        Self::animate(input, part)?.pop()
+    }
+
+    fn animate(input: &Self::Input, part: u32) -> Option<Vec<Picture>> {
+        let steps = match part {
+            1 => input.params.steps,
+            _ => when_everyone_flashes(&input.input),
+        };
+        let mut state = input.input.clone();
+        let mut frames = vec![state.picture()];
+        for _ in 0..steps {
+            state.step();
+            frames.push(state.picture());
+        }
+        Some(frames)
+    }
+}
@@ -0,0 +1,267 @@
+use crate::error::ParseError;
+use crate::random::Random;
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashMap;
+
+#[derive(Default, Debug)]
+pub struct Map {
+    paths: HashMap<String, Vec<String>>,
+}
+
+fn cave(line: &str, name: &str) -> Result<(), ParseError> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(())
+    } else {
+        Err(ParseError::at(12, line, name, "a cave name"))
+    }
+}
+
+impl TryFrom<Vec<&str>> for Map {
+    type Error = ParseError;
+
+    fn try_from(links: Vec<&str>) -> Result<Self, Self::Error> {
+        let mut map = Map::default();
+        for (n, link) in links.iter().enumerate() {
+            let (from, to) = link
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(12, link, "", "a path from-to").on_line(n + 1))?;
+            cave(link, from)
+                .and_then(|_| cave(link, to))
+                .map_err(|e| e.on_line(n + 1))?;
+
+            map.paths
+                .entry(from.to_string())
+                .and_modify(|c| c.push(to.to_string()))
+                .or_insert_with(|| vec![to.to_string()]);
+
+            map.paths
+                .entry(to.to_string())
+                .and_modify(|c| c.push(from.to_string()))
+                .or_insert_with(|| vec![from.to_string()]);
+        }
+
+        if !map.paths.contains_key("start") {
+            return Err(ParseError::new(12, links.len() + 1, 1, "a path from start"));
+        }
+        Ok(map)
+    }
+}
+
+impl Map {
+    fn walk(&self, next: &String, from: Vec<String>, visit_twice: bool) -> usize {
+        let mut count = 0;
+        for exit in self.paths.get(next).unwrap() {
+            if exit == "end" {
+                count += 1;
+                continue;
+            }
+
+            if exit == "start" {
+                continue;
+            }
+
+            if *exit == exit.to_ascii_lowercase() {
+                // small cave
+                if visit_twice {
+                    let have_looped = from
+                        .iter()
+                        .chain([next])
+                        .filter(|&s| *s == s.to_ascii_lowercase())
+                        .counts()
+                        .values()
+                        .any(|&x| x == 2);
+
+                    if have_looped && from.contains(exit) {
+                        continue;
+                    }
+                } else if from.contains(exit) {
+                    continue;
+                }
+            }
+
+            let mut path = from.clone();
+            path.push(next.to_string());
+            count += self.walk(exit, path, visit_twice);
+        }
+        count
+    }
+
+    fn count_paths(&self) -> usize {
+        self.walk(&"start".to_string(), vec![], false)
+    }
+
+    fn count_paths_advanced(&self) -> usize {
+        self.walk(&"start".to_string(), vec![], true)
+    }
+}
+
+#[test]
+fn test_paths() {
+    let example = r#"
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+"#;
+
+    let input = example.trim().split('\n').collect::<Vec<_>>();
+    let map = Map::try_from(input).unwrap();
+
+    assert_eq!(map.count_paths(), 10);
+    assert_eq!(map.count_paths_advanced(), 36);
+
+    let example = r#"
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+"#;
+
+    let input = example.trim().split('\n').collect::<Vec<_>>();
+    let map = Map::try_from(input).unwrap();
+
+    assert_eq!(map.count_paths(), 19);
+    assert_eq!(map.count_paths_advanced(), 103);
+
+    let example = r#"
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+"#;
+
+    let input = example.trim().split('\n').collect::<Vec<_>>();
+    let map = Map::try_from(input).unwrap();
+
+    assert_eq!(map.count_paths(), 226);
+    assert_eq!(map.count_paths_advanced(), 3509);
+}
+
+#[aoc_generator(day12)]
+fn generate(input: &str) -> Result<Map, ParseError> {
+    Map::try_from(input.lines().collect_vec())
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("start-A\nA-end\nA=b").err(),
+        Some(ParseError::new(12, 3, 4, "a path from-to"))
+    );
+    assert_eq!(
+        generate("start-A\nA-3").err(),
+        Some(ParseError::new(12, 2, 3, "a cave name"))
+    );
+    assert_eq!(
+        generate("A-b\nA-end").err(),
+        Some(ParseError::new(12, 3, 1, "a path from start"))
+    );
+}
+
+#[aoc(day12, part1)]
+fn count_paths(map: &Map) -> usize {
+    map.count_paths()
+}
+
+#[aoc(day12, part2)]
+fn count_paths_advanced(map: &Map) -> usize {
+    map.count_paths_advanced()
+}
+
+fn big(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_ascii_uppercase())
+}
+
+// `size` caves between start and end, one in four of them big.  Two big caves
+// are never joined, or there would be no end to the paths.
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut caves = vec![String::from("start")];
+    for n in 0..size.max(1) {
+        loop {
+            let name: String = (0..2).map(|_| rng.gen_range(b'a'..=b'z') as char).collect();
+            let name = if n % 4 == 3 {
+                name.to_ascii_uppercase()
+            } else {
+                name
+            };
+            if !caves.contains(&name) {
+                caves.push(name);
+                break;
+            }
+        }
+    }
+    caves.push(String::from("end"));
+
+    let joinable = |a: usize, b: usize| !(big(&caves[a]) && big(&caves[b]));
+
+    // A tree first, so every cave can be reached, then some shortcuts
+    let mut links = vec![];
+    for to in 1..caves.len() {
+        let from = loop {
+            let from = rng.gen_range(0..to);
+            if joinable(from, to) {
+                break from;
+            }
+        };
+        links.push((from, to));
+    }
+    for _ in 0..caves.len() / 2 {
+        let (a, b) = (rng.gen_range(0..caves.len()), rng.gen_range(0..caves.len()));
+        if a != b && joinable(a, b) && !links.contains(&(a, b)) && !links.contains(&(b, a)) {
+            links.push((a, b));
+        }
+    }
+    links.shuffle(rng);
+    links
+        .iter()
+        .map(|&(a, b)| format!("{}-{}", caves[a], caves[b]))
+        .join("\n")
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Map;
+    const RANDOM_SIZE: usize = 10;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        count_paths(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        count_paths_advanced(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
@@ -0,0 +1,328 @@
+use crate::error::{number, ParseError};
+use crate::geom::{Bounds, Vec2};
+use crate::random::Random;
+use crate::render::{plot, Picture, Rgb};
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+fn parse_point(s: &str) -> Result<Vec2, ParseError> {
+    if let Some((x, y)) = s.split_once(',') {
+        Ok(Vec2::new(
+            number::<u32>(13, s, x)?.into(),
+            number::<u32>(13, s, y)?.into(),
+        ))
+    } else {
+        Err(ParseError::at(13, s, "", "a point x,y"))
+    }
+}
+
+// Mirror a point on the far side of a fold to the near side
+fn fold_y(p: Vec2, line: i64) -> Vec2 {
+    if p.y > line {
+        Vec2::new(p.x, 2 * line - p.y)
+    } else {
+        p
+    }
+}
+
+fn fold_x(p: Vec2, line: i64) -> Vec2 {
+    if p.x > line {
+        Vec2::new(2 * line - p.x, p.y)
+    } else {
+        p
+    }
+}
+
+#[test]
+fn test_point_folding() {
+    assert_eq!(fold_y(Vec2::new(0, 0), 10), Vec2::new(0, 0));
+    assert_eq!(fold_y(Vec2::new(4, 20), 10), Vec2::new(4, 0));
+    assert_eq!(fold_y(Vec2::new(5, 11), 10), Vec2::new(5, 9));
+
+    assert_eq!(fold_x(Vec2::new(0, 0), 3), Vec2::new(0, 0));
+    assert_eq!(fold_x(Vec2::new(4, 20), 3), Vec2::new(2, 20));
+    assert_eq!(fold_x(Vec2::new(5, 11), 3), Vec2::new(1, 11));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Fold {
+    AlongX(i64),
+    AlongY(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    points: Vec<Vec2>,
+    commands: Vec<Fold>,
+}
+
+fn fold_command(command: &str) -> Result<Fold, ParseError> {
+    match command.split_once('=') {
+        Some(("fold along x", line)) => Ok(Fold::AlongX(number::<u32>(13, command, line)?.into())),
+        Some(("fold along y", line)) => Ok(Fold::AlongY(number::<u32>(13, command, line)?.into())),
+        _ => Err(ParseError::at(13, command, command, "fold along x= or y=")),
+    }
+}
+
+impl TryFrom<Vec<&str>> for Puzzle {
+    type Error = ParseError;
+
+    fn try_from(input: Vec<&str>) -> Result<Self, Self::Error> {
+        let points = input
+            .iter()
+            .take_while(|s| !s.is_empty())
+            .enumerate()
+            .map(|(n, &s)| parse_point(s).map_err(|e| e.on_line(n + 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // the folds follow the points and a blank line, and part 1 needs one
+        let first_fold = points.len() + 2;
+        let commands = input
+            .iter()
+            .skip(first_fold - 1)
+            .enumerate()
+            .map(|(n, &s)| fold_command(s).map_err(|e| e.on_line(n + first_fold)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if commands.is_empty() {
+            return Err(ParseError::new(13, first_fold, 1, "fold along x= or y="));
+        }
+
+        Ok(Puzzle { points, commands })
+    }
+}
+
+impl Puzzle {
+    fn apply_command(&mut self, index: usize) {
+        let fold = self.commands[index];
+        self.points = self
+            .points
+            .iter()
+            .map(|&p| match fold {
+                Fold::AlongX(x) => fold_x(p, x),
+                Fold::AlongY(y) => fold_y(p, y),
+            })
+            .unique()
+            .collect();
+    }
+
+    fn apply_commands(&mut self) {
+        for i in 0..self.commands.len() {
+            self.apply_command(i)
+        }
+    }
+
+    fn render(&self) -> String {
+        let max = match Bounds::of(self.points.iter().copied()) {
+            Some(bounds) => bounds.max,
+            None => return String::new(),
+        };
+        (0..=max.y)
+            .map(|y| {
+                (0..=max.x)
+                    .map(|x| {
+                        if self.points.contains(&Vec2::new(x, y)) {
+                            '#'
+                        } else {
+                            ' '
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+impl Puzzle {
+    // Dots in black on white paper, the top left corner of the paper kept
+    fn picture(&self) -> Picture {
+        let corner = (Vec2::ZERO, Rgb::WHITE);
+        plot(
+            std::iter::once(corner).chain(self.points.iter().map(|&p| (p, Rgb::BLACK))),
+            Rgb::WHITE,
+        )
+    }
+}
+
+#[test]
+fn test_puzzle() {
+    let example = r#"
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+"#;
+
+    let mut puzzle = Puzzle::try_from(example.trim().split('\n').collect::<Vec<&str>>()).unwrap();
+    assert_eq!(puzzle.points.len(), 18);
+    assert_eq!(puzzle.commands.len(), 2);
+
+    puzzle.apply_command(0);
+    assert_eq!(puzzle.points.len(), 17);
+
+    let mut puzzle = Puzzle::try_from(example.trim().split('\n').collect::<Vec<&str>>()).unwrap();
+    puzzle.apply_commands();
+    assert_eq!(puzzle.points.len(), 16);
+    assert_eq!(puzzle.render(), "#####\n#   #\n#   #\n#   #\n#####");
+    assert_eq!(
+        puzzle
+            .picture()
+            .render(|&c| if c == Rgb::BLACK { '#' } else { ' ' }),
+        puzzle.render()
+    );
+}
+
+#[aoc_generator(day13)]
+fn generate(input: &str) -> Result<Puzzle, ParseError> {
+    Puzzle::try_from(input.lines().collect_vec())
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("6,10\n0;14\n\nfold along y=7").err(),
+        Some(ParseError::new(13, 2, 5, "a point x,y"))
+    );
+    assert_eq!(
+        generate("6,10\n0,14\n\nfold along y=7\nfold along z=5").err(),
+        Some(ParseError::new(13, 5, 1, "fold along x= or y="))
+    );
+    assert_eq!(
+        generate("6,10\n0,14\n\nfold along x=-5").err(),
+        Some(ParseError::new(13, 4, 14, "a number"))
+    );
+    assert_eq!(
+        generate("1,2").err(),
+        Some(ParseError::new(13, 3, 1, "fold along x= or y="))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(13, 2, 1, "fold along x= or y="))
+    );
+    assert_eq!(step2(&generate("\nfold along y=1").unwrap()), "");
+}
+
+#[aoc(day13, part1)]
+fn step1(puzzle: &Puzzle) -> usize {
+    let mut puzzle = (*puzzle).clone();
+    puzzle.apply_command(0);
+    puzzle.points.len()
+}
+
+#[aoc(day13, part2)]
+fn step2(puzzle: &Puzzle) -> String {
+    let mut puzzle = (*puzzle).clone();
+    puzzle.apply_commands();
+    puzzle.render()
+}
+
+// Some blots of ink 40 by 6, unfolded five times along x and seven along y,
+// each time putting the points on either side of the fold, until there are
+// `size` or so of them
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut ink = (0..40)
+        .cartesian_product(0..6)
+        .filter(|_| rng.gen_bool(0.4))
+        .map(Vec2::from)
+        .collect_vec();
+    if ink.is_empty() {
+        ink.push(Vec2::ZERO);
+    }
+
+    let mut axes = [['x'; 5].as_slice(), &['y'; 7]].concat();
+    axes.shuffle(rng);
+    // Unfolding, from the last fold back to the first
+    let (mut width, mut height) = (40, 6);
+    let mut unfolds = vec![];
+    for axis in axes {
+        if axis == 'x' {
+            unfolds.push((axis, width));
+            width = 2 * width + 1;
+        } else {
+            unfolds.push((axis, height));
+            height = 2 * height + 1;
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut points = vec![];
+    for n in 0..size.max(ink.len()) {
+        let mut p = if n < ink.len() {
+            ink[n]
+        } else {
+            ink[rng.gen_range(0..ink.len())]
+        };
+        for &(axis, line) in &unfolds {
+            if rng.gen_bool(0.5) {
+                match axis {
+                    'x' => p.x = 2 * line - p.x,
+                    _ => p.y = 2 * line - p.y,
+                }
+            }
+        }
+        if seen.insert(p) {
+            points.push(p);
+        }
+    }
+
+    let folds = unfolds
+        .iter()
+        .rev()
+        .map(|(axis, line)| format!("fold along {}={}", axis, line));
+    points
+        .iter()
+        .map(|p| p.to_string())
+        .chain([String::new()])
+        .chain(folds)
+        .join("\n")
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Puzzle;
+    const RANDOM_SIZE: usize = 800;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        step1(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        step2(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn render(input: &Self::Input, part: u32) -> Option<Picture> {
+        let mut puzzle = input.clone();
+        match part {
+            1 => puzzle.apply_command(0),
+            _ => puzzle.apply_commands(),
+        }
+        Some(puzzle.picture())
+    }
+}
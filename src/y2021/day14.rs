@@ -0,0 +1,340 @@
+use crate::error::{parse_lines, ParseError};
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::Random;
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub struct Puzzle {
+    start: String,
+    rules: HashMap<(char, char), char>,
+}
+
+fn rule(line: &str) -> Result<((char, char), char), ParseError> {
+    match line.split_once(" -> ") {
+        Some((a, b)) if a.chars().count() == 2 && b.chars().count() == 1 => {
+            #[allow(clippy::iter_nth_zero)]
+            // we want 0 and 1, so nth(0) -> next() makes the nth(1) look funky
+            Ok((
+                (a.chars().nth(0).unwrap(), a.chars().nth(1).unwrap()),
+                b.chars().nth(0).unwrap(),
+            ))
+        }
+        _ => Err(ParseError::at(14, line, line, "a rule AB -> C")),
+    }
+}
+
+impl TryFrom<&str> for Puzzle {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (start, rules) = input.split_once("\n\n").unwrap_or((input, ""));
+        if start.is_empty() || start.contains('\n') {
+            return Err(ParseError::new(14, 1, 1, "a polymer template"));
+        }
+        let puzzle = Puzzle {
+            start: start.to_string(),
+            rules: HashMap::from_iter(parse_lines(rules, rule).map_err(|e| e.on_line(3))?),
+        };
+        puzzle.check_rules(rules.lines().count() + 3)?;
+        Ok(puzzle)
+    }
+}
+
+impl Puzzle {
+    // Every pair the polymer can ever hold needs a rule, starting with the
+    // template's own.  Those only made by inserting are missing from after
+    // the last rule, at `end`.
+    fn check_rules(&self, end: usize) -> Result<(), ParseError> {
+        let missing = |(a, b)| format!("a rule for {}{}", a, b);
+        let mut seen = HashSet::new();
+        for (column, pair) in self.start.chars().tuple_windows().enumerate() {
+            if !self.rules.contains_key(&pair) {
+                return Err(ParseError::new(14, 1, column + 1, missing(pair)));
+            }
+            seen.insert(pair);
+        }
+
+        let mut todo = seen.iter().copied().collect_vec();
+        while let Some(pair @ (a, b)) = todo.pop() {
+            let insert = self.rules[&pair];
+            for next in [(a, insert), (insert, b)] {
+                if !self.rules.contains_key(&next) {
+                    return Err(ParseError::new(14, end, 1, missing(next)));
+                }
+                if seen.insert(next) {
+                    todo.push(next);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Puzzle {
+    fn step_simple(&self, s: String) -> String {
+        let mut result: Vec<String> = vec![];
+        s.chars().tuple_windows::<(_, _)>().for_each(|(a, b)| {
+            result.push(a.to_string());
+            result.push(self.rules.get(&(a, b)).unwrap().to_string());
+        });
+        result.push(s.chars().last().unwrap().to_string());
+        result.join("")
+    }
+
+    fn steps(&self, step: usize) -> String {
+        let mut result = self.start.clone();
+        for _ in 0..step {
+            result = self.step_simple(result);
+        }
+        result
+    }
+
+    fn step_1(&self, steps: usize) -> usize {
+        let polymer = self.steps(steps);
+        let counts = polymer.chars().counts();
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    fn step_pairs(&self, counts: HashMap<(char, char), usize>) -> HashMap<(char, char), usize> {
+        let mut new_pairs = HashMap::new();
+        for (pair, count) in counts {
+            let &insert = self.rules.get(&pair).unwrap();
+            new_pairs
+                .entry((pair.0, insert))
+                .and_modify(|v| *v += count)
+                .or_insert(count);
+            new_pairs
+                .entry((insert, pair.1))
+                .and_modify(|v| *v += count)
+                .or_insert(count);
+        }
+        new_pairs
+    }
+
+    fn step_pairwise(&self, count: usize) -> HashMap<(char, char), usize> {
+        // Pairs can repeat in the template, so count them rather than collect
+        let mut counts = self.start.chars().tuple_windows::<(_, _)>().counts();
+        for _ in 0..count {
+            counts = self.step_pairs(counts);
+        }
+        counts
+    }
+
+    // How many of each element, worked out from the pairs after `count` steps
+    fn element_counts(&self, count: usize) -> HashMap<char, usize> {
+        let pair_counts = self.step_pairwise(count);
+        let mut char_counts = HashMap::new();
+        pair_counts.iter().for_each(|(&(a, _), &count)| {
+            char_counts
+                .entry(a)
+                .and_modify(|v| *v += count)
+                .or_insert(count);
+        });
+
+        let last_char = self.start.chars().last().unwrap();
+        char_counts
+            .entry(last_char)
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+        char_counts
+    }
+
+    fn step_2(&self, steps: usize) -> usize {
+        let char_counts = self.element_counts(steps);
+        char_counts.values().max().unwrap() - char_counts.values().min().unwrap()
+    }
+}
+
+#[test]
+fn test_expand() {
+    let example = r#"
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+"#;
+
+    let puzzle = Puzzle::try_from(example.trim()).unwrap();
+    assert_eq!(puzzle.rules.len(), 16);
+    assert_eq!(puzzle.steps(1), "NCNBCHB");
+    assert_eq!(puzzle.steps(2), "NBCCNBBBCBHCB");
+    assert_eq!(puzzle.steps(3), "NBBBCNCCNBBNBNBBCHBHHBCHB");
+    assert_eq!(
+        puzzle.steps(4),
+        "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"
+    );
+
+    assert_eq!(puzzle.step_1(10), 1588);
+    // Naiive polymer code blows up memory real bad after 40 steps
+    assert_eq!(puzzle.step_2(10), 1588);
+    assert_eq!(puzzle.step_2(40), 2188189693529);
+}
+
+#[test]
+fn test_repeated_pairs() {
+    // A pair that comes up twice in the template counts twice
+    let repeating = Puzzle::try_from("PKPK\n\nPK -> P\nKP -> K\nPP -> K\nKK -> P").unwrap();
+    assert_eq!(
+        repeating.element_counts(0),
+        repeating.steps(0).chars().counts()
+    );
+}
+
+#[test]
+fn test_step_pairwise_matches_simple() {
+    use crate::differential::assert_agree;
+    use crate::random::seeded;
+
+    // A puzzle's text and how many steps to take; shrinks by shortening the
+    // template or taking fewer steps
+    assert_agree(
+        0..20,
+        |seed| {
+            let mut rng = seeded(seed);
+            let steps = rng.gen_range(0..=8);
+            (random_input(&mut rng, 6), steps)
+        },
+        |(input, steps)| {
+            let (template, rules) = input.split_once("\n\n").unwrap();
+            let mut smaller = vec![];
+            if *steps > 0 {
+                smaller.push((input.clone(), steps - 1));
+            }
+            if template.len() > 2 {
+                for skip in 0..template.len() {
+                    let mut shorter = template.to_string();
+                    shorter.remove(skip);
+                    smaller.push((format!("{}\n\n{}", shorter, rules), *steps));
+                }
+            }
+            smaller
+        },
+        |(input, steps)| {
+            let puzzle = Puzzle::try_from(input.as_str()).unwrap();
+            puzzle.steps(*steps).chars().counts()
+        },
+        |(input, steps)| {
+            Puzzle::try_from(input.as_str())
+                .unwrap()
+                .element_counts(*steps)
+        },
+    );
+}
+
+#[aoc_generator(day14)]
+fn generate(input: &str) -> Result<Puzzle, ParseError> {
+    Puzzle::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("NNCB\n\nCH -> B\nHH - N").err(),
+        Some(ParseError::new(14, 4, 1, "a rule AB -> C"))
+    );
+    assert_eq!(
+        generate("NNCB\nCH -> B").err(),
+        Some(ParseError::new(14, 1, 1, "a polymer template"))
+    );
+    assert_eq!(
+        generate("NNCB\n\nCH -> B").err(),
+        Some(ParseError::new(14, 1, 1, "a rule for NN"))
+    );
+    assert_eq!(
+        generate("NN\n\nNN -> C\nNC -> N").err(),
+        Some(ParseError::new(14, 5, 1, "a rule for CN"))
+    );
+}
+
+// How many insertion steps each part takes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day14Params {
+    pub part1_steps: usize,
+    pub part2_steps: usize,
+}
+
+impl Default for Day14Params {
+    fn default() -> Self {
+        Day14Params {
+            part1_steps: 10,
+            part2_steps: 40,
+        }
+    }
+}
+
+impl Params for Day14Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part1_steps" => self.part1_steps = params::between(name, value, 0, 20)?,
+            "part2_steps" => self.part2_steps = params::between(name, value, 0, 50)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[aoc(day14, part1)]
+fn step1(p: &Puzzle) -> usize {
+    p.step_1(Day14Params::default().part1_steps)
+}
+
+#[aoc(day14, part2)]
+fn step2(p: &Puzzle) -> usize {
+    p.step_2(Day14Params::default().part2_steps)
+}
+
+// A template of `size` from ten elements, with a rule for every pair of them
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let elements = "BCFHKNOPSV".chars().collect_vec();
+    let pick = |rng: &mut Random| elements[rng.gen_range(0..elements.len())];
+    let template: String = (0..size.max(2)).map(|_| pick(rng)).collect();
+    let rules = elements
+        .iter()
+        .cartesian_product(&elements)
+        .map(|(a, b)| format!("{}{} -> {}", a, b, pick(rng)))
+        .join("\n");
+    format!("{}\n\n{}", template, rules)
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Configured<Puzzle, Day14Params>;
+    const RANDOM_SIZE: usize = 20;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        input.input.step_1(input.params.part1_steps).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        input.input.step_2(input.params.part2_steps).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+}
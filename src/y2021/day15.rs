@@ -0,0 +1,327 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::{digit, grid, Random};
+use crate::render::{shade, Picture, Rgb};
+use crate::solver::Solver;
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+#[derive(Debug)]
+pub struct Puzzle {
+    map: Grid<u8>,
+}
+
+impl TryFrom<&str> for Puzzle {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse_digits(15, input)?;
+        if map.width() == 0 {
+            return Err(ParseError::new(15, 1, 1, "a digit"));
+        }
+        Ok(Puzzle { map })
+    }
+}
+
+type Point = (usize, usize);
+
+#[derive(PartialEq, Eq)]
+struct State {
+    cost: usize,
+    position: Point,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Puzzle {
+    fn embiggen(&self, repeat: usize) -> Self {
+        let (width, height) = (self.map.width(), self.map.height());
+        let mut new_map = Grid::new(width * repeat, height * repeat, 0);
+        for (x, y) in new_map.positions().collect::<Vec<_>>() {
+            let risk = self.map[(x % width, y % height)] as usize + x / width + y / height;
+            // Wrap 9 to 1
+            new_map[(x, y)] = ((risk - 1) % 9 + 1) as u8;
+        }
+        Puzzle { map: new_map }
+    }
+
+    fn shortest_path(&self) -> usize {
+        self.route().0
+    }
+
+    // The lowest total risk to the bottom right corner, and the way there
+    fn route(&self) -> (usize, Vec<Point>) {
+        let start = (0, 0);
+        let end = (self.map.width() - 1, self.map.height() - 1);
+
+        // Dijkstra's algorithm - from the manpage for std::collections::binaryheap
+        let mut dist = Grid::new(self.map.width(), self.map.height(), usize::MAX);
+        dist[start] = 0;
+        let mut prev = Grid::new(self.map.width(), self.map.height(), None);
+
+        let mut queue = BinaryHeap::new();
+        queue.push(State {
+            cost: 0,
+            position: start,
+        });
+
+        while let Some(State { cost, position }) = queue.pop() {
+            if position == end {
+                let mut path = vec![end];
+                while let Some(p) = prev[*path.last().unwrap()] {
+                    path.push(p);
+                }
+                path.reverse();
+                return (cost, path);
+            }
+
+            if cost > dist[position] {
+                continue;
+            }
+
+            for location in self.map.neighbours4(position) {
+                let next = State {
+                    cost: cost + self.map[location] as usize,
+                    position: location,
+                };
+
+                if next.cost < dist[location] {
+                    dist[location] = next.cost;
+                    prev[location] = Some(position);
+                    queue.push(next);
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    // The risk map in greys, darker is safer, with the route over it in red
+    fn picture(&self) -> Picture {
+        let mut picture = self.map.map(|&risk| shade(risk as u64, 9));
+        for p in self.route().1 {
+            picture[p] = Rgb::RED;
+        }
+        picture
+    }
+}
+
+#[test]
+fn test_puzzle() {
+    let example = r#"
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+"#;
+
+    let puzzle = Puzzle::try_from(example.trim()).unwrap();
+    assert_eq!((puzzle.map.width(), puzzle.map.height()), (10, 10));
+
+    assert_eq!(puzzle.map[(0, 0)], 1);
+    assert_eq!(puzzle.map[(9, 0)], 2);
+    assert_eq!(puzzle.shortest_path(), 40);
+    let (risk, route) = puzzle.route();
+    assert_eq!(route.len(), 19);
+    assert_eq!(route.first(), Some(&(0, 0)));
+    assert_eq!(route.last(), Some(&(9, 9)));
+    assert_eq!(
+        route
+            .iter()
+            .skip(1)
+            .map(|&p| puzzle.map[p] as usize)
+            .sum::<usize>(),
+        risk
+    );
+    assert_eq!(puzzle.picture()[(0, 1)], Rgb::RED);
+    assert_eq!(puzzle.picture()[(1, 0)], shade(1, 9));
+
+    let example_embiggened = r#"
+11637517422274862853338597396444961841755517295286
+13813736722492484783351359589446246169155735727126
+21365113283247622439435873354154698446526571955763
+36949315694715142671582625378269373648937148475914
+74634171118574528222968563933317967414442817852555
+13191281372421239248353234135946434524615754563572
+13599124212461123532357223464346833457545794456865
+31254216394236532741534764385264587549637569865174
+12931385212314249632342535174345364628545647573965
+23119445813422155692453326671356443778246755488935
+22748628533385973964449618417555172952866628316397
+24924847833513595894462461691557357271266846838237
+32476224394358733541546984465265719557637682166874
+47151426715826253782693736489371484759148259586125
+85745282229685639333179674144428178525553928963666
+24212392483532341359464345246157545635726865674683
+24611235323572234643468334575457944568656815567976
+42365327415347643852645875496375698651748671976285
+23142496323425351743453646285456475739656758684176
+34221556924533266713564437782467554889357866599146
+33859739644496184175551729528666283163977739427418
+35135958944624616915573572712668468382377957949348
+43587335415469844652657195576376821668748793277985
+58262537826937364893714847591482595861259361697236
+96856393331796741444281785255539289636664139174777
+35323413594643452461575456357268656746837976785794
+35722346434683345754579445686568155679767926678187
+53476438526458754963756986517486719762859782187396
+34253517434536462854564757396567586841767869795287
+45332667135644377824675548893578665991468977611257
+44961841755517295286662831639777394274188841538529
+46246169155735727126684683823779579493488168151459
+54698446526571955763768216687487932779859814388196
+69373648937148475914825958612593616972361472718347
+17967414442817852555392896366641391747775241285888
+46434524615754563572686567468379767857948187896815
+46833457545794456865681556797679266781878137789298
+64587549637569865174867197628597821873961893298417
+45364628545647573965675868417678697952878971816398
+56443778246755488935786659914689776112579188722368
+55172952866628316397773942741888415385299952649631
+57357271266846838237795794934881681514599279262561
+65719557637682166874879327798598143881961925499217
+71484759148259586125936169723614727183472583829458
+28178525553928963666413917477752412858886352396999
+57545635726865674683797678579481878968159298917926
+57944568656815567976792667818781377892989248891319
+75698651748671976285978218739618932984172914319528
+56475739656758684176786979528789718163989182927419
+67554889357866599146897761125791887223681299833479
+"#;
+
+    let example_embiggened = Puzzle::try_from(example_embiggened.trim()).unwrap();
+
+    let embiggened = puzzle.embiggen(5);
+    assert_eq!(embiggened.map, example_embiggened.map);
+    assert_eq!((embiggened.map.width(), embiggened.map.height()), (50, 50));
+    assert_eq!(embiggened.map[(0, 10)], 2);
+    assert_eq!(embiggened.shortest_path(), 315);
+}
+
+#[test]
+fn test_puzzle_embiggen() {
+    let puzzle = Puzzle::try_from("18").unwrap();
+    assert_eq!((puzzle.map.width(), puzzle.map.height()), (2, 1));
+    assert_eq!(puzzle.map.to_string(), "18");
+
+    let embiggened = puzzle.embiggen(5);
+    assert_eq!((embiggened.map.width(), embiggened.map.height()), (10, 5));
+    assert_eq!(
+        embiggened.map.rows().take(2).collect::<Vec<_>>(),
+        [
+            [1, 8, 2, 9, 3, 1, 4, 2, 5, 3],
+            [2, 9, 3, 1, 4, 2, 5, 3, 6, 4]
+        ]
+    );
+
+    assert_eq!(puzzle.embiggen(1).map, puzzle.map);
+    // Risks keep wrapping however far out the tiles go
+    assert_eq!(puzzle.embiggen(200).map[(398, 199)], 3);
+}
+
+#[aoc_generator(day15)]
+fn generate(input: &str) -> Result<Puzzle, ParseError> {
+    Puzzle::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("1163\n1381\n21365").err(),
+        Some(ParseError::new(15, 3, 5, "4 columns"))
+    );
+    assert_eq!(
+        generate("1163\n13 1").err(),
+        Some(ParseError::new(15, 2, 3, "a digit"))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(15, 1, 1, "a digit"))
+    );
+}
+
+// Part 2's map is this many copies of the input across and down, up to 20
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day15Params {
+    pub tiles: usize,
+}
+
+impl Default for Day15Params {
+    fn default() -> Self {
+        Day15Params { tiles: 5 }
+    }
+}
+
+impl Params for Day15Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "tiles" => self.tiles = params::between(name, value, 1, 20)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[aoc(day15, part1)]
+fn shortest_path(p: &Puzzle) -> usize {
+    p.shortest_path()
+}
+
+#[aoc(day15, part2)]
+fn shortest_path_expanded(p: &Puzzle) -> usize {
+    p.embiggen(Day15Params::default().tiles).shortest_path()
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    grid(rng, size, size, |rng| digit(rng, 1..=9))
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Configured<Puzzle, Day15Params>;
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        shortest_path(&input.input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        let embiggened = input.input.embiggen(input.params.tiles);
+        embiggened.shortest_path().to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+
+    fn render(input: &Self::Input, part: u32) -> Option<Picture> {
+        match part {
+            1 => Some(input.input.picture()),
+            _ => Some(input.input.embiggen(input.params.tiles).picture()),
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use crate::error::ParseError;
+use crate::random::Random;
+use crate::solver::Solver;
+use bytes::Bytes;
+use rand::Rng;
+
+fn to_binary(c: char) -> &'static [u8] {
+    match c {
+        '0' => b"0000",
+        '1' => b"0001",
+        '2' => b"0010",
+        '3' => b"0011",
+        '4' => b"0100",
+        '5' => b"0101",
+        '6' => b"0110",
+        '7' => b"0111",
+        '8' => b"1000",
+        '9' => b"1001",
+        'A' => b"1010",
+        'B' => b"1011",
+        'C' => b"1100",
+        'D' => b"1101",
+        'E' => b"1110",
+        'F' => b"1111",
+        _ => unreachable!(),
+    }
+}
+
+fn hex_to_bits(s: &str) -> Bytes {
+    Bytes::from(s.chars().flat_map(to_binary).copied().collect::<Vec<u8>>())
+}
+
+#[aoc_generator(day16)]
+fn generate(input: &str) -> Result<String, ParseError> {
+    let line = input.trim();
+    match line
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '0'..='9' | 'A'..='F'))
+    {
+        Some((i, _)) => return Err(ParseError::at(16, line, &line[i..], "a hex digit")),
+        None if line.is_empty() => return Err(ParseError::new(16, 1, 1, "a hex digit")),
+        None => (),
+    }
+
+    // The whole transmission has to decode, so the parts can take it as read
+    let bits = hex_to_bits(line);
+    let column = |remaining: usize| (bits.len() - remaining) / 4 + 1;
+    match decode(&bits) {
+        Err(e) => Err(ParseError::new(16, 1, column(e.remaining), e.expected)),
+        Ok((padding, _)) if padding.contains(&b'1') => Err(ParseError::new(
+            16,
+            1,
+            column(padding.len()),
+            "only padding after the packet",
+        )),
+        Ok(_) => Ok(line.to_string()),
+    }
+}
+
+#[test]
+fn test_generate() {
+    assert_eq!(generate("D2FE28\n"), Ok(String::from("D2FE28")));
+    assert_eq!(
+        generate("D2FG28"),
+        Err(ParseError::new(16, 1, 4, "a hex digit"))
+    );
+    assert_eq!(
+        generate("0"),
+        Err(ParseError::new(16, 1, 1, "the rest of the packet"))
+    );
+    assert_eq!(generate(""), Err(ParseError::new(16, 1, 1, "a hex digit")));
+    // 38006F45291200 with the length of its sub-packets running past the end
+    assert_eq!(
+        generate("3800FF45291200"),
+        Err(ParseError::new(16, 1, 6, "the rest of the packet"))
+    );
+    // C200B40A82, 1 + 2, made a less-than of only its first packet
+    assert_eq!(
+        generate("DA00740A82"),
+        Err(ParseError::new(16, 1, 8, "two sub-packets to compare"))
+    );
+    assert_eq!(
+        generate("D2FE29"),
+        Err(ParseError::new(16, 1, 6, "only padding after the packet"))
+    );
+}
+
+#[test]
+fn test_hex_to_bits() {
+    assert_eq!(hex_to_bits("D2FE28"), "110100101111111000101000");
+}
+
+type Number = u64;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Value {
+    Literal(Number),
+    Operation { kind: Number, on: Vec<Packet> },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Packet {
+    version: Number,
+    value: Value,
+}
+
+fn decode_binary(bits: &[u8]) -> Number {
+    let mut value = 0;
+    for b in bits {
+        value = (value << 1) + (b - b'0') as Number
+    }
+    value
+}
+
+#[test]
+fn test_decode_binary() {
+    assert_eq!(decode_binary(b"0"), 0);
+    assert_eq!(decode_binary(b"1"), 1);
+    assert_eq!(decode_binary(b"10"), 2);
+    assert_eq!(decode_binary(b"11"), 3);
+    assert_eq!(decode_binary(b"100"), 4);
+}
+
+// Where a transmission stopped making sense, as how many bits were still to
+// come, and what we hoped to find there
+#[derive(Debug, PartialEq)]
+struct DecodeError {
+    remaining: usize,
+    expected: &'static str,
+}
+
+type Decoded<'a, T> = Result<(&'a [u8], T), DecodeError>;
+
+fn take(bits: &[u8], n: usize) -> Decoded<'_, &[u8]> {
+    if bits.len() < n {
+        return Err(DecodeError {
+            remaining: bits.len(),
+            expected: "the rest of the packet",
+        });
+    }
+    Ok((&bits[n..], &bits[..n]))
+}
+
+fn decode_literal(mut bits: &[u8]) -> Decoded<'_, Value> {
+    let mut value: Number = 0;
+    loop {
+        if value >> (Number::BITS - 4) != 0 {
+            return Err(DecodeError {
+                remaining: bits.len(),
+                expected: "a literal that fits in 64 bits",
+            });
+        }
+        let chunk;
+        (bits, chunk) = take(bits, 5)?;
+        value = (value << 4) + decode_binary(&chunk[1..]);
+
+        if chunk[0] == b'0' {
+            return Ok((bits, Value::Literal(value)));
+        }
+    }
+}
+
+fn decode_operation(kind: Number, bits: &[u8]) -> Decoded<'_, Value> {
+    let (bits, length_type) = take(bits, 1)?;
+    let mut packets = vec![];
+    let rem = if length_type[0] == b'0' {
+        // next 15 bits are a length, parse up to length worth of subpackets
+        let (bits, len) = take(bits, 15)?;
+        let (rem, mut inner) = take(bits, decode_binary(len) as usize)?;
+        // at least a version+kind+4 (smallest value) left to parse
+        while inner.len() > 10 {
+            let packet;
+            (inner, packet) = decode_packet(inner).map_err(|e| DecodeError {
+                remaining: e.remaining + rem.len(),
+                ..e
+            })?;
+            packets.push(packet);
+        }
+        rem
+    } else {
+        // next 11 bits are a count, parse count subpackets
+        let (mut rem, count) = take(bits, 11)?;
+        for _ in 0..decode_binary(count) {
+            let packet;
+            (rem, packet) = decode_packet(rem)?;
+            packets.push(packet);
+        }
+        rem
+    };
+
+    let expected = match kind {
+        2 | 3 if packets.is_empty() => "at least one sub-packet",
+        5..=7 if packets.len() != 2 => "two sub-packets to compare",
+        _ => return Ok((rem, Value::Operation { kind, on: packets })),
+    };
+    Err(DecodeError {
+        remaining: rem.len(),
+        expected,
+    })
+}
+
+fn decode(bits: &[u8]) -> Decoded<'_, Packet> {
+    decode_packet(bits)
+}
+
+fn decode_packet(bits: &[u8]) -> Decoded<'_, Packet> {
+    let (bits, version) = take(bits, 3)?;
+    let (bits, packet_type) = take(bits, 3)?;
+    let version = decode_binary(version);
+
+    let (remainder, value) = match decode_binary(packet_type) {
+        4 => decode_literal(bits)?,
+        kind => decode_operation(kind, bits)?,
+    };
+    Ok((remainder, Packet { version, value }))
+}
+
+#[test]
+fn test_decode_packet_literal() {
+    assert_eq!(
+        decode(&hex_to_bits("D2FE28")).unwrap(),
+        (
+            b"000".as_slice(), // unused padding
+            Packet {
+                version: 6,
+                value: Value::Literal(2021)
+            }
+        )
+    )
+}
+
+#[test]
+fn test_decode_packet_operator() {
+    assert_eq!(
+        decode(&hex_to_bits("38006F45291200")).unwrap(),
+        (
+            b"0000000".as_slice(),
+            Packet {
+                version: 1,
+                value: Value::Operation {
+                    kind: 6,
+                    on: vec![
+                        Packet {
+                            version: 6,
+                            value: Value::Literal(10)
+                        },
+                        Packet {
+                            version: 2,
+                            value: Value::Literal(20)
+                        },
+                    ]
+                }
+            }
+        )
+    );
+
+    assert_eq!(
+        decode(&hex_to_bits("EE00D40C823060")).unwrap(),
+        (
+            b"00000".as_slice(),
+            Packet {
+                version: 7,
+                value: Value::Operation {
+                    kind: 3,
+                    on: vec![
+                        Packet {
+                            version: 2,
+                            value: Value::Literal(1),
+                        },
+                        Packet {
+                            version: 4,
+                            value: Value::Literal(2)
+                        },
+                        Packet {
+                            version: 1,
+                            value: Value::Literal(3)
+                        },
+                    ],
+                }
+            }
+        )
+    );
+}
+
+fn walk_versions(p: &Packet) -> Number {
+    match &p.value {
+        Value::Literal(_) => p.version,
+        Value::Operation { on, .. } => p.version + on.iter().map(walk_versions).sum::<Number>(),
+    }
+}
+
+#[aoc(day16, part1)]
+fn sum_versions(s: &str) -> Number {
+    // generate has already made sure it decodes
+    let (_, packet) = decode(&hex_to_bits(s)).unwrap();
+    walk_versions(&packet)
+}
+
+#[test]
+fn test_sum_versions() {
+    assert_eq!(sum_versions("8A004A801A8002F478"), 16);
+    assert_eq!(sum_versions("620080001611562C8802118E34"), 12);
+    assert_eq!(sum_versions("C0015000016115A2E0802F182340"), 23);
+    assert_eq!(sum_versions("A0016C880162017C3686B18A3D4780"), 31);
+}
+
+fn eval_list(on: &[Packet]) -> impl Iterator<Item = Number> + '_ {
+    on.iter().map(|p| {
+        if let Value::Literal(v) = eval(p.value.clone()) {
+            v
+        } else {
+            unreachable!()
+        }
+    })
+}
+
+fn eval(v: Value) -> Value {
+    match v {
+        Value::Literal(_) => v,
+        Value::Operation { kind, on, .. } => match kind {
+            // sum
+            0 => Value::Literal(eval_list(&on).sum()),
+            // product
+            1 => Value::Literal(eval_list(&on).product()),
+            // min
+            2 => Value::Literal(eval_list(&on).min().unwrap()),
+            // max
+            3 => Value::Literal(eval_list(&on).max().unwrap()),
+            // greater-than
+            5 => {
+                let mut values = eval_list(&on);
+                let first = values.next().unwrap();
+                let second = values.next().unwrap();
+                Value::Literal(if first > second { 1 } else { 0 })
+            }
+            // 6 => (), // less-than
+            6 => {
+                let mut values = eval_list(&on);
+                let first = values.next().unwrap();
+                let second = values.next().unwrap();
+                Value::Literal(if first < second { 1 } else { 0 })
+            }
+            // 7 => (), // equals
+            7 => {
+                let mut values = eval_list(&on);
+                let first = values.next().unwrap();
+                let second = values.next().unwrap();
+                Value::Literal(if first == second { 1 } else { 0 })
+            }
+            _ => unreachable!(),
+        },
+    }
+}
+
+impl Packet {
+    #[allow(dead_code)] // used to construct eval tests
+    fn literal(n: Number) -> Self {
+        Packet {
+            version: 42,
+            value: Value::Literal(n),
+        }
+    }
+}
+
+#[test]
+fn test_eval() {
+    assert_eq!(
+        eval(Value::Operation {
+            kind: 0,
+            on: vec![
+                Packet::literal(1),
+                Packet::literal(1),
+                Packet::literal(1),
+                Packet::literal(1)
+            ],
+        }),
+        Value::Literal(4),
+        "sum(1,1,1,1) == 4"
+    );
+
+    assert_eq!(
+        eval(Value::Operation {
+            kind: 0,
+            on: vec![Packet::literal(128)],
+        }),
+        Value::Literal(128),
+        "sum(128) == 128"
+    );
+
+    assert_eq!(
+        eval(Value::Operation {
+            kind: 1,
+            on: vec![
+                Packet::literal(2),
+                Packet::literal(3),
+                Packet::literal(4),
+                Packet::literal(5)
+            ]
+        }),
+        Value::Literal(120),
+        "product(2,3,4,5) == 120"
+    );
+
+    assert_eq!(
+        eval(Value::Operation {
+            kind: 2,
+            on: vec![Packet::literal(200)],
+        }),
+        Value::Literal(200),
+        "product(200) == 200"
+    );
+}
+
+#[aoc(day16, part2)]
+fn eval_wrapped(s: &str) -> Number {
+    let bits = hex_to_bits(s);
+    let (_, packet) = decode(&bits).unwrap();
+    if let Value::Literal(v) = eval(packet.value) {
+        return v;
+    }
+    unreachable!();
+}
+
+#[test]
+fn test_eval_wrapped() {
+    assert_eq!(eval_wrapped("C200B40A82"), 3, "1+2 == 3");
+    assert_eq!(eval_wrapped("04005AC33890"), 54, "6*9 == 54");
+    assert_eq!(eval_wrapped("880086C3E88112"), 7, "min(7,8,9) == 7");
+    assert_eq!(eval_wrapped("CE00C43D881120"), 9, "max(7,8,9) == 9");
+    assert_eq!(eval_wrapped("D8005AC2A8F0"), 1, "5 < 15 == 1");
+    assert_eq!(eval_wrapped("F600BC2D8F"), 0, "5 > 15 == 0");
+    assert_eq!(eval_wrapped("9C005AC2F8F0"), 0, "5 != 15 == 0");
+    assert_eq!(
+        eval_wrapped("9C0141080250320F1802104A08"),
+        1,
+        "(1 + 3) == (2 * 2)"
+    );
+}
+
+// A packet of `size` packets all told, as bits, and what it evaluates to
+fn random_packet(rng: &mut Random, size: usize) -> (String, Number) {
+    let mut bits = format!("{:03b}", rng.gen_range(0..8));
+    if size <= 1 {
+        let value: Number = rng.gen_range(0..5000);
+        bits.push_str("100");
+        let groups = (Number::BITS - value.leading_zeros()).max(1).div_ceil(4);
+        for g in (0..groups).rev() {
+            bits.push(if g == 0 { '0' } else { '1' });
+            bits.push_str(&format!("{:04b}", (value >> (4 * g)) & 0xf));
+        }
+        return (bits, value);
+    }
+
+    // Any kind but a literal, the comparisons taking just the two packets
+    let mut kind = match rng.gen_range(0..7) {
+        k if k >= 4 => k + 1,
+        k => k,
+    };
+    let count = if kind >= 5 { 2 } else { rng.gen_range(1..=3) };
+    let mut sizes = vec![1; count];
+    for _ in count..size - 1 {
+        sizes[rng.gen_range(0..count)] += 1;
+    }
+    let (subpackets, values): (Vec<String>, Vec<Number>) =
+        sizes.into_iter().map(|n| random_packet(rng, n)).unzip();
+
+    // Products that grow too large become sums instead
+    let product = values
+        .iter()
+        .try_fold(1 as Number, |acc, &v| acc.checked_mul(v))
+        .filter(|&p| p < 1 << 40);
+    if kind == 1 && product.is_none() {
+        kind = 0;
+    }
+    let value = match kind {
+        0 => values.iter().sum(),
+        1 => product.unwrap(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as Number,
+        6 => (values[0] < values[1]) as Number,
+        _ => (values[0] == values[1]) as Number,
+    };
+
+    bits.push_str(&format!("{:03b}", kind));
+    let subpackets = subpackets.concat();
+    if subpackets.len() < 1 << 15 && rng.gen_bool(0.5) {
+        bits.push_str(&format!("0{:015b}", subpackets.len()));
+    } else {
+        bits.push_str(&format!("1{:011b}", count));
+    }
+    bits.push_str(&subpackets);
+    (bits, value)
+}
+
+fn to_hex(bits: &str) -> String {
+    // Padded out with zeros to a whole number of hex digits
+    let bits = format!("{:0<1$}", bits, bits.len().div_ceil(4) * 4);
+    bits.as_bytes()
+        .chunks(4)
+        .map(|nibble| format!("{:X}", decode_binary(nibble)))
+        .collect()
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    to_hex(&random_packet(rng, size.max(1)).0)
+}
+
+#[test]
+fn test_random_packet() {
+    use crate::random::seeded;
+
+    let mut rng = seeded(16);
+    for size in [1, 2, 10, 50] {
+        let (bits, value) = random_packet(&mut rng, size);
+        assert_eq!(eval_wrapped(&to_hex(&bits)), value);
+    }
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = String;
+    const RANDOM_SIZE: usize = 50;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        sum_versions(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        eval_wrapped(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
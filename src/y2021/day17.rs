@@ -0,0 +1,343 @@
+use crate::error::ParseError;
+use crate::geom::{Bounds, Vec2};
+use crate::random::Random;
+use crate::solver::Solver;
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1},
+    combinator::{map_res, recognize, verify},
+    error::ErrorKind,
+    sequence::{pair, preceded, separated_pair},
+    IResult,
+};
+use rand::Rng;
+
+#[derive(Debug, PartialEq)]
+pub struct Target {
+    area: Bounds<Vec2>,
+}
+
+fn parse_signed_number(input: &str) -> IResult<&str, i64> {
+    map_res(
+        alt((recognize(pair(char('-'), digit1)), digit1)),
+        str::parse,
+    )(input)
+}
+
+fn parse_range(input: &str) -> IResult<&str, (i64, i64)> {
+    let (input, (start, end)) = verify(
+        separated_pair(parse_signed_number, tag(".."), parse_signed_number),
+        |(start, end)| start <= end,
+    )(input)?;
+
+    Ok((input, (start, end)))
+}
+
+fn parse_target(input: &str) -> IResult<&str, (i64, i64, i64, i64)> {
+    let (input, ((x1, x2), (y1, y2))) = pair(
+        preceded(tag("target area: x="), parse_range),
+        preceded(tag(", y="), parse_range),
+    )(input)?;
+
+    Ok((input, (x1, x2, y1, y2)))
+}
+
+#[test]
+fn test_parser() {
+    assert_eq!(
+        parse_target("target area: x=2..20, y=-20..-10"),
+        Ok(("", (2, 20, -20, -10)))
+    );
+}
+
+impl TryFrom<&str> for Target {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match parse_target(s) {
+            // The probe only ever heads forward from x=0
+            Ok((_, (x1, _, _, _))) if x1 < 0 => Err(ParseError::at(
+                17,
+                s,
+                s.trim_start_matches("target area: x="),
+                "a target at x=0 or beyond",
+            )),
+            Ok((_, (x1, x2, y1, y2))) => Ok(Target {
+                area: Bounds::new(Vec2::new(x1, y1), Vec2::new(x2, y2)),
+            }),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) if e.code == ErrorKind::MapRes => Err(
+                ParseError::at(17, s, e.input, "a number that fits in 64 bits"),
+            ),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) if e.code == ErrorKind::Verify => {
+                Err(ParseError::at(17, s, e.input, "a range from low to high"))
+            }
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(
+                17,
+                s,
+                e.input,
+                "target area: x=X1..X2, y=Y1..Y2",
+            )),
+            Err(nom::Err::Incomplete(_)) => {
+                Err(ParseError::at(17, s, "", "target area: x=X1..X2, y=Y1..Y2"))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_target_from() {
+    assert_eq!(
+        Target::try_from("target area: x=20..30, y=-10..-5").unwrap(),
+        Target {
+            area: Bounds {
+                min: Vec2::new(20, -10),
+                max: Vec2::new(30, -5)
+            }
+        }
+    );
+}
+
+impl Target {
+    fn hit(&self, p: Vec2) -> bool {
+        self.area.contains(p)
+    }
+}
+
+#[test]
+fn test_target_hit() {
+    let target = Target::try_from("target area: x=20..30, y=-10..-5").unwrap();
+
+    assert_eq!(target.hit(Vec2::new(20, -5)), true);
+    assert_eq!(target.hit(Vec2::new(30, -5)), true);
+    assert_eq!(target.hit(Vec2::new(20, -10)), true);
+    assert_eq!(target.hit(Vec2::new(30, -10)), true);
+    assert_eq!(target.hit(Vec2::new(0, 0)), false);
+    assert_eq!(target.hit(Vec2::new(0, -10)), false);
+}
+
+impl Target {
+    // Gone past, or fallen below
+    fn missed(&self, p: Vec2) -> bool {
+        p.x > self.area.max.x || p.y < self.area.min.y
+    }
+}
+
+#[test]
+fn test_target_missed() {
+    let target = Target::try_from("target area: x=20..30, y=-10..-5").unwrap();
+
+    assert_eq!(target.missed(Vec2::new(30, -10)), false);
+    assert_eq!(target.missed(Vec2::new(0, 0)), false);
+    assert_eq!(target.missed(Vec2::new(30, -11)), true);
+    assert_eq!(target.missed(Vec2::new(31, -10)), true);
+}
+
+#[derive(Debug, PartialEq)]
+struct Probe {
+    p: Vec2,
+    v: Vec2,
+}
+
+impl Probe {
+    fn launch(x: i64, y: i64) -> Self {
+        Self {
+            p: Vec2::ZERO,
+            v: Vec2::new(x, y),
+        }
+    }
+
+    fn step(&mut self) {
+        self.p += self.v;
+
+        // drag slows us towards 0, and gravity pulls us down
+        self.v -= Vec2::new(self.v.x.signum(), 1);
+    }
+}
+
+#[test]
+fn test_probe() {
+    let mut probe = Probe::launch(7, 2);
+    assert_eq!(
+        probe,
+        Probe {
+            p: Vec2::new(0, 0),
+            v: Vec2::new(7, 2)
+        }
+    );
+    probe.step();
+    assert_eq!(
+        probe,
+        Probe {
+            p: Vec2::new(7, 2),
+            v: Vec2::new(6, 1)
+        }
+    );
+
+    probe.step();
+    assert_eq!(
+        probe,
+        Probe {
+            p: Vec2::new(13, 3),
+            v: Vec2::new(5, 0)
+        }
+    );
+
+    probe.step();
+    assert_eq!(
+        probe,
+        Probe {
+            p: Vec2::new(18, 3),
+            v: Vec2::new(4, -1)
+        }
+    );
+}
+
+fn find_height(t: &Target, x: i64, y: i64) -> Option<i64> {
+    let mut probe = Probe::launch(x, y);
+    let mut max = 0;
+    loop {
+        if probe.p.y > max {
+            max = probe.p.y;
+        }
+
+        if t.hit(probe.p) {
+            return Some(max);
+        }
+
+        if t.missed(probe.p) {
+            return None;
+        }
+
+        probe.step();
+    }
+}
+
+#[test]
+fn test_find_height() {
+    let target = Target::try_from("target area: x=20..30, y=-10..-5").unwrap();
+
+    assert_eq!(find_height(&target, 17, -4), None);
+
+    assert_eq!(find_height(&target, 7, 2), Some(3));
+    assert_eq!(find_height(&target, 6, 3), Some(6));
+    assert_eq!(find_height(&target, 9, 0), Some(0));
+
+    assert_eq!(find_height(&target, 6, 9), Some(45));
+}
+
+#[aoc_generator(day17)]
+fn generate(input: &str) -> Result<Target, ParseError> {
+    Target::try_from(input)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("target area: x=20..30, z=-10..-5").err(),
+        Some(ParseError::new(
+            17,
+            1,
+            22,
+            "target area: x=X1..X2, y=Y1..Y2"
+        ))
+    );
+    assert_eq!(
+        generate("target area: x=20..30, y=-10..").err(),
+        Some(ParseError::new(
+            17,
+            1,
+            31,
+            "target area: x=X1..X2, y=Y1..Y2"
+        ))
+    );
+    assert_eq!(
+        generate("target area: x=20..99999999999999999999, y=-10..-5").err(),
+        Some(ParseError::new(17, 1, 20, "a number that fits in 64 bits"))
+    );
+    assert_eq!(
+        generate("target area: x=20..30, y=-5..-10").err(),
+        Some(ParseError::new(17, 1, 26, "a range from low to high"))
+    );
+    assert_eq!(
+        generate("target area: x=-30..-20, y=-10..-5").err(),
+        Some(ParseError::new(17, 1, 16, "a target at x=0 or beyond"))
+    );
+}
+
+// Probably woefully ineffienct to search such a wide space rather than
+// calculating some bounds relative to the target
+fn find_max_height(t: &Target) -> Option<i64> {
+    (0..1000)
+        .cartesian_product(0..1000)
+        .filter_map(|(x, y)| find_height(t, x, y))
+        .max()
+}
+
+#[test]
+fn test_find_max_height() {
+    let target = Target::try_from("target area: x=20..30, y=-10..-5").unwrap();
+    assert_eq!(find_max_height(&target), Some(45));
+
+    // Only reached by firing down, straight at it
+    let target = Target::try_from("target area: x=5..5, y=-100..-100").unwrap();
+    assert_eq!(find_max_height(&target), None);
+    assert_eq!(highest(&target), "no launch upwards hits the target");
+}
+
+#[aoc(day17, part1)]
+fn highest(t: &Target) -> String {
+    find_max_height(t).map_or_else(
+        || String::from("no launch upwards hits the target"),
+        |height| height.to_string(),
+    )
+}
+
+#[aoc(day17, part2)]
+fn count_firing_vectors(t: &Target) -> usize {
+    (0..1000)
+        .cartesian_product(-1000..1000)
+        .filter_map(|(x, y)| find_height(t, x, y))
+        .count()
+}
+
+#[test]
+fn test_count_firing_vectors() {
+    let target = Target::try_from("target area: x=20..30, y=-10..-5").unwrap();
+    assert_eq!(count_firing_vectors(&target), 112);
+}
+
+// A launch of n across stops moving across at n(n+1)/2, so a target around
+// there, and deep enough that the probe is still falling once it stops, can
+// always be hit by going up first
+fn random_input(rng: &mut Random, _size: usize) -> String {
+    let n: i64 = rng.gen_range(6..40);
+    let stop = n * (n + 1) / 2;
+    let (x1, x2) = (stop - rng.gen_range(0..n), stop + rng.gen_range(0..20));
+    let y2 = -rng.gen_range(5..=50);
+    let y1 = (y2 - rng.gen_range(5..=40)).min(-n);
+    format!("target area: x={}..{}, y={}..{}", x1, x2, y1, y2)
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        highest(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        count_firing_vectors(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
@@ -0,0 +1,399 @@
+use super::day18_parsed_trees::{self as trees, parse_pair, Number, Pair};
+use crate::error::{parse_lines, ParseError};
+use crate::random::{lines, Random};
+use crate::solver::{Solver, Variant};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use nom::error::ErrorKind;
+use rand::Rng;
+use regex::{Captures, Regex};
+
+// The string rewriting we first solved this with, kept to check the trees
+// in day18_parsed_trees.rs against
+
+fn add(lhs: &str, rhs: &str) -> String {
+    format!("[{},{}]", lhs, rhs)
+}
+
+#[test]
+fn test_add() {
+    assert_eq!(add("[1,2]", "[2,3]"), "[[1,2],[2,3]]")
+}
+
+fn split(s: &str) -> String {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"\d{2,}").unwrap();
+    }
+
+    if let Some(m) = RE.find(s) {
+        let left = &s[0..m.start()];
+        let num = m.as_str().parse::<i32>().unwrap();
+        let right = &s[m.end()..];
+
+        format!("{}[{},{}]{}", left, num / 2, (num + 1) / 2, right)
+    } else {
+        s.to_string()
+    }
+}
+
+#[test]
+fn test_split() {
+    assert_eq!(split("[0,9]"), "[0,9]");
+    assert_eq!(split("[0,10]"), "[0,[5,5]]");
+    assert_eq!(split("[10,10]"), "[[5,5],10]");
+    assert_eq!(split("[0,11]"), "[0,[5,6]]");
+    assert_eq!(split("[0,12]"), "[0,[6,6]]");
+}
+
+fn explode(s: &str) -> String {
+    fn exploding_pair(s: &str) -> Option<(usize, usize)> {
+        let mut depth = 0;
+        let mut start: Option<usize> = None;
+        for (i, c) in s.char_indices() {
+            if let Some(start) = start {
+                if c == ']' {
+                    return Some((start, i + 1));
+                }
+            } else if c == '[' {
+                depth += 1;
+
+                if depth == 5 {
+                    start = Some(i)
+                }
+            } else if c == ']' {
+                depth -= 1;
+            }
+        }
+        None
+    }
+
+    if let Some((start, end)) = exploding_pair(s) {
+        let left = &s[0..start];
+        let right = &s[end..];
+
+        // Get the values from the exploding pair
+        let (vl, vr) = &s[start + 1..end - 1].split_once(',').unwrap();
+        let vl = vl.parse::<i32>().unwrap();
+        let vr = vr.parse::<i32>().unwrap();
+
+        // replace rightmost number with its value plus the right value of the exploding pair
+        lazy_static! {
+            static ref RIGHT_RE: Regex = Regex::new(r"\d+").unwrap();
+        }
+        let right = RIGHT_RE.replace(right, |c: &Captures| {
+            let val = c.get(0).unwrap().as_str().parse::<i32>().unwrap();
+            format!("{}", val + vr)
+        });
+
+        // like replacing on the right, but we must match from the end
+        lazy_static! {
+            static ref LEFT_RE: Regex = Regex::new(r"(\d+)([^\d]*)\z").unwrap();
+        }
+        let left = LEFT_RE.replace(left, |c: &Captures| {
+            let val = c.get(1).unwrap().as_str().parse::<i32>().unwrap();
+            format!("{}{}", val + vl, c.get(2).unwrap().as_str())
+        });
+
+        format!("{}0{}", left, right)
+    } else {
+        s.to_string()
+    }
+}
+
+#[test]
+fn test_explode() {
+    assert_eq!(explode("[[[[[9,8],1],2],3],4]"), "[[[[0,9],2],3],4]");
+    assert_eq!(explode("[7,[6,[5,[4,[3,2]]]]]"), "[7,[6,[5,[7,0]]]]");
+    assert_eq!(explode("[[6,[5,[4,[3,2]]]],1]"), "[[6,[5,[7,0]]],3]");
+    assert_eq!(
+        explode("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]"),
+        "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"
+    );
+    assert_eq!(
+        explode("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"),
+        "[[3,[2,[8,0]]],[9,[5,[7,0]]]]"
+    );
+
+    assert_eq!(
+        explode("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]"),
+        "[[[[0,7],4],[15,[0,13]]],[1,1]]"
+    );
+}
+
+fn reduce(s: &str) -> String {
+    let exploded = explode(s);
+    if exploded != s {
+        return reduce(&exploded);
+    }
+
+    let split = split(s);
+    if split != s {
+        return reduce(&split);
+    }
+
+    s.to_string()
+}
+
+#[test]
+fn test_reduce() {
+    assert_eq!(
+        reduce("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"),
+        "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+    )
+}
+
+fn add_set(s: &[&str]) -> String {
+    let mut res: String = s[0].to_string();
+    for s in s.iter().skip(1) {
+        res = reduce(&add(&res, s))
+    }
+    res
+}
+
+#[test]
+fn test_add_set() {
+    assert_eq!(
+        add_set(&["[1,1]", "[2,2]", "[3,3]", "[4,4]"]),
+        "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+    );
+
+    assert_eq!(
+        add_set(&["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]"]),
+        "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+    );
+
+    assert_eq!(
+        add_set(&["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]),
+        "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+    );
+
+    assert_eq!(
+        add_set(&[
+            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+            "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+            "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+            "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+            "[7,[5,[[3,8],[1,4]]]]",
+            "[[2,[2,2]],[8,[8,1]]]",
+            "[2,9]",
+            "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+            "[[[5,[7,4]],7],1]",
+            "[[[[4,2],2],6],[8,7]]"
+        ]),
+        "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+    );
+}
+
+fn magnitude(s: &str) -> Number {
+    Pair::from(s).magnitude()
+}
+
+#[test]
+fn test_magnitude() {
+    assert_eq!(magnitude("[9,1]"), 29);
+    assert_eq!(magnitude("[1,9]"), 21);
+    assert_eq!(magnitude("[[9,1],[1,9]]"), 129);
+    assert_eq!(magnitude("[[1,2],[[3,4],5]]"), 143);
+    assert_eq!(magnitude("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"), 1384);
+    assert_eq!(magnitude("[[[[1,1],[2,2]],[3,3]],[4,4]]"), 445);
+    assert_eq!(magnitude("[[[[3,0],[5,3]],[4,4]],[5,5]]"), 791);
+    assert_eq!(magnitude("[[[[5,0],[7,4]],[5,5]],[6,6]]"), 1137);
+    assert_eq!(
+        magnitude("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"),
+        3488
+    );
+}
+
+// The homework comes reduced, which both ways of adding rely on: explode
+// only takes apart pairs of regular numbers, and regular numbers under 10
+// keep every sum well inside a Number
+fn reduced(line: &str) -> Result<(), ParseError> {
+    let mut depth = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '[' if depth == 4 => {
+                return Err(ParseError::at(
+                    18,
+                    line,
+                    &line[i..],
+                    "a regular number, as pairs nest at most four deep",
+                ))
+            }
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '0'..='9' if line[i + 1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                return Err(ParseError::at(
+                    18,
+                    line,
+                    &line[i..],
+                    "a regular number under 10",
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[aoc_generator(day18)]
+fn generate(input: &str) -> Result<Vec<Pair>, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::new(18, 1, 1, "a snailfish number"));
+    }
+    parse_lines(input, |line| match parse_pair(line) {
+        Ok(("", number)) => reduced(line).map(|_| number),
+        Ok((rest, _)) => Err(ParseError::at(18, line, rest, "the end of the line")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) if e.code == ErrorKind::MapRes => Err(
+            ParseError::at(18, line, e.input, "a number that fits in 32 bits"),
+        ),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(ParseError::at(18, line, e.input, "a snailfish number"))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(18, line, "", "a snailfish number")),
+    })
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("[1,2]\n[[1,2],3]]").err(),
+        Some(ParseError::new(18, 2, 10, "the end of the line"))
+    );
+    assert_eq!(
+        generate("[1,2]\n[[1,2] 3]").err(),
+        Some(ParseError::new(18, 2, 7, "a snailfish number"))
+    );
+    assert_eq!(
+        generate("[[1,2],[99999999999,1]]").err(),
+        Some(ParseError::new(18, 1, 9, "a number that fits in 32 bits"))
+    );
+    assert_eq!(
+        generate("[[[[[9,8],1],2],3],4]").err(),
+        Some(ParseError::new(
+            18,
+            1,
+            5,
+            "a regular number, as pairs nest at most four deep"
+        ))
+    );
+    assert_eq!(
+        generate("[1,2]\n[[1,2],10]").err(),
+        Some(ParseError::new(18, 2, 8, "a regular number under 10"))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(18, 1, 1, "a snailfish number"))
+    );
+}
+
+#[aoc(day18, part1)]
+fn magnitude_of_all(numbers: &[Pair]) -> Number {
+    trees::add_set(numbers).magnitude()
+}
+
+#[aoc(day18, part2)]
+fn topscore(numbers: &[Pair]) -> Number {
+    trees::topscore(numbers)
+}
+
+fn written_out(numbers: &[Pair]) -> Vec<String> {
+    numbers.iter().map(Pair::to_string).collect()
+}
+
+#[aoc(day18, part1, strings)]
+fn magnitude_of_all_strings(numbers: &[Pair]) -> Number {
+    let numbers = written_out(numbers);
+    magnitude(&add_set(&numbers.iter().map(String::as_str).collect_vec()))
+}
+
+#[aoc(day18, part2, strings)]
+fn topscore_strings(numbers: &[Pair]) -> Number {
+    written_out(numbers)
+        .iter()
+        .permutations(2)
+        .map(|v| magnitude(&reduce(&add(v[0], v[1]))))
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_topscore_single_number() {
+    let numbers = generate("[1,2]").unwrap();
+    assert_eq!(magnitude_of_all(&numbers), 7);
+    assert_eq!(topscore(&numbers), 0);
+    assert_eq!(topscore_strings(&numbers), 0);
+}
+
+// A number already reduced, so pairs nested no more than four deep and
+// regular numbers under 10
+fn random_number(rng: &mut Random, depth: usize) -> String {
+    if depth > 4 || (depth > 1 && rng.gen_bool(0.4)) {
+        rng.gen_range(0..10).to_string()
+    } else {
+        let left = random_number(rng, depth + 1);
+        let right = random_number(rng, depth + 1);
+        format!("[{},{}]", left, right)
+    }
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    lines(rng, size.max(1), |rng| random_number(rng, 1))
+}
+
+#[test]
+fn test_reduce_matches_trees() {
+    use crate::differential::{assert_agree, shrink_vec};
+    use crate::random::seeded;
+
+    assert_agree(
+        0..50,
+        |seed| {
+            let mut rng = seeded(seed);
+            (0..10).map(|_| random_number(&mut rng, 1)).collect_vec()
+        },
+        |numbers| shrink_vec(numbers),
+        |numbers| add_set(&numbers.iter().map(String::as_str).collect_vec()),
+        |numbers| {
+            trees::add_set(&numbers.iter().map(|n| Pair::from(n.as_str())).collect_vec())
+                .to_string()
+        },
+    );
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Vec<Pair>;
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        magnitude_of_all(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        topscore(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: 1,
+                name: "strings",
+                run: |input| magnitude_of_all_strings(input).to_string(),
+            },
+            Variant {
+                part: 2,
+                name: "strings",
+                run: |input| topscore_strings(input).to_string(),
+            },
+        ]
+    }
+}
@@ -0,0 +1,347 @@
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1},
+    combinator::{cut, map, map_res, peek},
+    sequence::{delimited, preceded, separated_pair},
+    IResult,
+};
+use std::{fmt, ops};
+
+pub type Number = u32;
+
+// A snailfish number, as a tree of pairs down to regular numbers
+#[derive(Debug, PartialEq, Clone)]
+pub enum Pair {
+    Number(Number),
+    Pair(Box<Self>, Box<Self>),
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pair::Number(v) => write!(f, "{}", v),
+            Pair::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl Pair {
+    fn value(&self) -> Number {
+        if let Pair::Number(v) = self {
+            *v
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[test]
+fn test_pair_display() {
+    assert_eq!("2", format!("{}", Pair::Number(2)));
+    assert_eq!(
+        "[2,3]",
+        format!(
+            "{}",
+            Pair::Pair(Box::new(Pair::Number(2)), Box::new(Pair::Number(3)))
+        )
+    );
+
+    assert_eq!(
+        "[2,[3,4]]",
+        format!(
+            "{}",
+            Pair::Pair(
+                Box::new(Pair::Number(2)),
+                Box::new(Pair::Pair(
+                    Box::new(Pair::Number(3)),
+                    Box::new(Pair::Number(4))
+                ))
+            )
+        )
+    );
+}
+
+// Once a digit turns up it's a regular number or nothing, so one too big
+// fails outright rather than letting alt go on to try a pair
+fn parse_number(input: &str) -> IResult<&str, Pair> {
+    preceded(
+        peek(digit1),
+        cut(map(map_res(digit1, str::parse::<Number>), Pair::Number)),
+    )(input)
+}
+
+pub fn parse_pair(input: &str) -> IResult<&str, Pair> {
+    let (input, (left, right)) = delimited(
+        char('['),
+        separated_pair(
+            alt((parse_number, parse_pair)),
+            tag(","),
+            alt((parse_number, parse_pair)),
+        ),
+        char(']'),
+    )(input)?;
+
+    Ok((input, Pair::Pair(Box::new(left), Box::new(right))))
+}
+
+#[test]
+fn test_parse_pair() {
+    assert_eq!(
+        Ok((
+            "",
+            Pair::Pair(Box::new(Pair::Number(2)), Box::new(Pair::Number(3)))
+        )),
+        parse_pair("[2,3]")
+    );
+
+    assert_eq!(
+        Ok((
+            "",
+            Pair::Pair(
+                Box::new(Pair::Number(2)),
+                Box::new(Pair::Pair(
+                    Box::new(Pair::Number(3)),
+                    Box::new(Pair::Number(4))
+                ))
+            )
+        )),
+        parse_pair("[2,[3,4]]")
+    );
+}
+
+impl From<&str> for Pair {
+    fn from(input: &str) -> Self {
+        if let Ok((_, pair)) = parse_pair(input) {
+            pair
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[test]
+fn test_pair_from_strref() {
+    assert_eq!(
+        Pair::from("[1,2]]"),
+        Pair::Pair(Box::new(Pair::Number(1)), Box::new(Pair::Number(2)))
+    );
+}
+
+impl PartialEq<&str> for Pair {
+    fn eq(&self, other: &&str) -> bool {
+        let other = Pair::from(*other);
+        *self == other
+    }
+}
+
+#[test]
+fn test_pair_eq_strref() {
+    assert_eq!(Pair::from("[1,2]"), "[1,2]");
+}
+
+impl ops::Add for Pair {
+    type Output = Pair;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Pair::Pair(Box::new(self), Box::new(rhs))
+    }
+}
+
+#[test]
+fn test_pair_add() {
+    assert_eq!(
+        Pair::from("[1,2]") + Pair::from("[[3,4],5]"),
+        "[[1,2],[[3,4],5]]"
+    );
+}
+
+impl Pair {
+    fn add_leftmost(&mut self, n: Number) {
+        match self {
+            Pair::Number(v) => *v += n,
+            Pair::Pair(left, _) => left.add_leftmost(n),
+        }
+    }
+
+    fn add_rightmost(&mut self, n: Number) {
+        match self {
+            Pair::Number(v) => *v += n,
+            Pair::Pair(_, right) => right.add_rightmost(n),
+        }
+    }
+
+    // Explodes the leftmost pair nested inside four others, if there is one,
+    // handing back the numbers still to be added to its neighbours on either
+    // side once we are out of the branch that held it
+    fn explode_at(&mut self, depth: usize) -> Option<(Option<Number>, Option<Number>)> {
+        match self {
+            Pair::Number(_) => None,
+            Pair::Pair(left, right) if depth >= 4 => {
+                let (l, r) = (left.value(), right.value());
+                *self = Pair::Number(0);
+                Some((Some(l), Some(r)))
+            }
+            Pair::Pair(left, right) => {
+                if let Some((l, r)) = left.explode_at(depth + 1) {
+                    if let Some(r) = r {
+                        right.add_leftmost(r);
+                    }
+                    return Some((l, None));
+                }
+                if let Some((l, r)) = right.explode_at(depth + 1) {
+                    if let Some(l) = l {
+                        left.add_rightmost(l);
+                    }
+                    return Some((None, r));
+                }
+                None
+            }
+        }
+    }
+
+    #[allow(dead_code)] // a single step, for the tests
+    fn explode(mut self) -> Self {
+        self.explode_at(0);
+        self
+    }
+}
+
+#[test]
+fn test_pair_explode() {
+    assert_eq!(
+        Pair::from("[[[[[9,8],1],2],3],4]").explode(),
+        "[[[[0,9],2],3],4]"
+    );
+
+    assert_eq!(
+        Pair::from("[7,[6,[5,[4,[3,2]]]]]").explode(),
+        "[7,[6,[5,[7,0]]]]"
+    );
+
+    assert_eq!(
+        Pair::from("[[6,[5,[4,[3,2]]]],1]").explode(),
+        "[[6,[5,[7,0]]],3]"
+    );
+
+    assert_eq!(
+        Pair::from("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").explode(),
+        "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"
+    );
+
+    assert_eq!(
+        Pair::from("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").explode(),
+        "[[3,[2,[8,0]]],[9,[5,[7,0]]]]"
+    );
+}
+
+impl Pair {
+    // Splits the leftmost number over 9, if there is one
+    fn split_first(&mut self) -> bool {
+        match self {
+            Pair::Number(x) if *x > 9 => {
+                let x = *x;
+                *self = Pair::Number(x / 2) + Pair::Number(x.div_ceil(2));
+                true
+            }
+            Pair::Pair(left, right) => left.split_first() || right.split_first(),
+            _ => false,
+        }
+    }
+
+    #[allow(dead_code)] // a single step, for the tests
+    fn split(mut self) -> Self {
+        self.split_first();
+        self
+    }
+}
+
+#[test]
+fn test_pair_split() {
+    assert_eq!(Pair::from("[0,9]").split(), "[0,9]");
+    assert_eq!(Pair::from("[0,10]").split(), "[0,[5,5]]");
+    assert_eq!(Pair::from("[10,10]").split(), "[[5,5],10]");
+    assert_eq!(Pair::from("[0,11]").split(), "[0,[5,6]]");
+    assert_eq!(Pair::from("[0,12]").split(), "[0,[6,6]]");
+}
+
+impl Pair {
+    fn reduce(mut self) -> Self {
+        while self.explode_at(0).is_some() || self.split_first() {}
+        self
+    }
+}
+
+#[test]
+fn test_pair_reduce() {
+    assert_eq!(
+        (Pair::from("[[[[4,3],4],4],[7,[[8,4],9]]]") + Pair::from("[1,1]")).reduce(),
+        "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+    );
+}
+
+impl Pair {
+    pub fn magnitude(&self) -> Number {
+        match self {
+            Pair::Number(v) => *v,
+            Pair::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+#[test]
+fn test_pair_magnitude() {
+    assert_eq!(Pair::from("[[1,2],[[3,4],5]]").magnitude(), 143);
+    assert_eq!(
+        Pair::from("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+        3488
+    );
+}
+
+// Adds up the numbers in order, reducing after every addition
+pub fn add_set(numbers: &[Pair]) -> Pair {
+    numbers
+        .iter()
+        .cloned()
+        .reduce(|sum, number| (sum + number).reduce())
+        .unwrap()
+}
+
+#[test]
+fn test_add_set() {
+    let numbers = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"].map(Pair::from);
+    assert_eq!(add_set(&numbers[..4]), "[[[[1,1],[2,2]],[3,3]],[4,4]]");
+    assert_eq!(add_set(&numbers), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+}
+
+// The largest magnitude of any two different numbers added, either way round
+// With fewer than two numbers there's no sum to score
+pub fn topscore(numbers: &[Pair]) -> Number {
+    numbers
+        .iter()
+        .permutations(2)
+        .map(|v| (v[0].clone() + v[1].clone()).reduce().magnitude())
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_topscore() {
+    let numbers = [
+        "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+        "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+        "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+        "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+        "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+        "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+        "[[[[5,4],[7,7]],8],[[8,3],8]]",
+        "[[9,3],[[9,9],[6,[4,9]]]]",
+        "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+        "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+    ]
+    .map(Pair::from);
+    assert_eq!(add_set(&numbers).magnitude(), 4140);
+    assert_eq!(topscore(&numbers), 3993);
+    assert_eq!(topscore(&numbers[..1]), 0);
+}
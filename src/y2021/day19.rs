@@ -0,0 +1,323 @@
+use crate::error::{number, ParseError};
+use crate::geom::{Bounds, Vector};
+use crate::geom::{Vec3, ROTATIONS};
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::Random;
+use crate::solver::Solver;
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+fn parse_position(s: &str) -> Result<Vec3, ParseError> {
+    let v = s
+        .split(',')
+        .map(|x| number(19, s, x))
+        .collect::<Result<Vec<_>, _>>()?;
+    if v.len() != 3 {
+        return Err(ParseError::at(19, s, s, "a position x,y,z"));
+    }
+    Ok(Vec3::new(v[0], v[1], v[2]))
+}
+
+#[derive(Default)]
+pub struct Scanner {
+    probes: Vec<Vec3>,
+}
+
+#[aoc_generator(day19)]
+fn generate(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    let mut scanners = vec![];
+    for (key, group) in &input.lines().enumerate().group_by(|(_, s)| s.is_empty()) {
+        if key {
+            continue;
+        }
+        let mut lines = group.into_iter();
+        // each scanner starts with a separator
+        if let Some((n, header)) = lines.next() {
+            if !(header.starts_with("--- scanner ") && header.ends_with(" ---")) {
+                return Err(ParseError::at(19, header, header, "--- scanner N ---").on_line(n + 1));
+            }
+        }
+        scanners.push(Scanner {
+            probes: lines
+                .map(|(n, l)| parse_position(l).map_err(|e| e.on_line(n + 1)))
+                .collect::<Result<_, _>>()?,
+        });
+    }
+    if scanners.is_empty() {
+        return Err(ParseError::new(19, 1, 1, "--- scanner N ---"));
+    }
+    Ok(scanners)
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("--- scanner 0 ---\n1,2,3\n\nscanner 1\n1,2,3").err(),
+        Some(ParseError::new(19, 4, 1, "--- scanner N ---"))
+    );
+    assert_eq!(
+        generate("--- scanner 0 ---\n1,2,3\n1,2\n").err(),
+        Some(ParseError::new(19, 3, 1, "a position x,y,z"))
+    );
+    assert_eq!(
+        generate("--- scanner 0 ---\n1,2,3\n1,2,-\n").err(),
+        Some(ParseError::new(19, 3, 5, "a number"))
+    );
+    assert_eq!(
+        generate("").err(),
+        Some(ParseError::new(19, 1, 1, "--- scanner N ---"))
+    );
+}
+
+#[test]
+fn test_generate() {
+    let scanners = generate(EXAMPLE).unwrap();
+    assert_eq!(scanners.len(), 5);
+    assert_eq!(scanners[0].probes.len(), 25);
+    assert_eq!(scanners[0].probes[0], Vec3::new(404, -588, -901));
+}
+
+impl Scanner {
+    fn get_orientations(&self) -> Vec<Vec<Vec3>> {
+        ROTATIONS
+            .iter()
+            .map(|&rotate| self.probes.iter().map(|&p| rotate * p).collect_vec())
+            .collect_vec()
+    }
+}
+
+#[test]
+fn test_rotations() {
+    let scanners = generate(include_str!("day19_small_example.txt")).unwrap();
+    let permutations = scanners[0].get_orientations();
+    assert_eq!(permutations.len(), 24);
+    assert_eq!(permutations[0], scanners[0].probes);
+    assert_eq!(permutations.iter().any(|p| *p == scanners[1].probes), true);
+
+    assert_eq!(
+        scanners
+            .iter()
+            .all(|scanner| permutations.contains(&scanner.probes)),
+        true
+    );
+}
+
+fn beacons_match(known: &mut HashSet<Vec3>, sensor: &Scanner, overlap: usize) -> Option<Vec3> {
+    let orientations = sensor.get_orientations();
+    for rotated in &orientations {
+        let distances = known
+            .iter()
+            .cartesian_product(rotated)
+            .map(|(known, check)| *known - *check);
+
+        for distance in distances {
+            let translated = rotated.iter().map(|p| *p + distance);
+            if translated.clone().filter(|p| known.contains(p)).count() >= overlap {
+                known.extend(translated);
+                return Some(distance);
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn test_beacons_match() {
+    let scanners = generate(include_str!("day19_example.txt")).unwrap();
+    let sensors = Vec::from_iter(&scanners[1..]);
+    let mut known: HashSet<Vec3> = HashSet::from_iter(scanners[0].probes.clone());
+
+    assert_eq!(
+        beacons_match(&mut known, sensors[0], 12),
+        Some(Vec3::new(68, -1246, -43))
+    );
+}
+
+fn assemble_points(scanners: &[Scanner], overlap: usize) -> (usize, Vec<Vec3>) {
+    let mut sensors = Vec::from_iter(&scanners[1..]);
+    let mut positions = vec![Vec3::ZERO];
+    let mut known: HashSet<Vec3> = HashSet::from_iter(scanners[0].probes.clone());
+    'outer: while !sensors.is_empty() {
+        for i in 0..sensors.len() {
+            if let Some(position) = beacons_match(&mut known, sensors[i], overlap) {
+                positions.push(position);
+                sensors.remove(i);
+                continue 'outer;
+            }
+        }
+        // We should match at least one sensor per pass
+        unreachable!()
+    }
+
+    (known.len(), positions)
+}
+
+// How many beacons two scanners have to see in common to be placed together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day19Params {
+    pub overlap: usize,
+}
+
+impl Default for Day19Params {
+    fn default() -> Self {
+        Day19Params { overlap: 12 }
+    }
+}
+
+impl Params for Day19Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "overlap" => self.overlap = params::at_least(name, value, 1)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[aoc(day19, part1)]
+fn count_beacons(scanners: &[Scanner]) -> usize {
+    assemble_points(scanners, Day19Params::default().overlap).0
+}
+
+const EXAMPLE: &str = include_str!("day19_example.txt");
+
+#[test]
+fn test_count_beacons() {
+    assert_eq!(count_beacons(&generate(EXAMPLE.trim()).unwrap()), 79);
+}
+
+#[aoc(day19, part2)]
+fn how_wide_was_it(scanners: &[Scanner]) -> i64 {
+    widest(&assemble_points(scanners, Day19Params::default().overlap).1)
+}
+
+fn widest(points: &[Vec3]) -> i64 {
+    points
+        .iter()
+        .cartesian_product(points.iter())
+        .map(|(&p1, &p2)| p1.manhattan(p2))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn test_how_wide_was_it() {
+    assert_eq!(how_wide_was_it(&generate(EXAMPLE.trim()).unwrap()), 3621);
+}
+
+// What a scanner at `at` can see
+fn reach(at: Vec3) -> Bounds<Vec3> {
+    Bounds::new(at - Vec3::splat(1000), at + Vec3::splat(1000))
+}
+
+fn random_within(rng: &mut Random, area: Bounds<Vec3>) -> Vec3 {
+    Vec3::new(
+        rng.gen_range(area.min.x..=area.max.x),
+        rng.gen_range(area.min.y..=area.max.y),
+        rng.gen_range(area.min.z..=area.max.z),
+    )
+}
+
+// The hidden map, as scanners and beacons.  The scanners are strung out one
+// after another, each sharing 12 beacons with the one before, and there are a
+// few more beacons dotted around each.
+fn random_map(rng: &mut Random, size: usize) -> (Vec<Vec3>, Vec<Vec3>) {
+    let mut scanners = vec![Vec3::ZERO];
+    let mut beacons = vec![];
+    let mut place = |rng: &mut Random, area: Bounds<Vec3>, count: usize| {
+        let mut placed = 0;
+        while placed < count {
+            let beacon = random_within(rng, area);
+            if !beacons.contains(&beacon) {
+                beacons.push(beacon);
+                placed += 1;
+            }
+        }
+    };
+    for _ in 1..size.max(1) {
+        let last = scanners[scanners.len() - 1];
+        let step = random_within(rng, Bounds::new(Vec3::splat(-1200), Vec3::splat(1200)));
+        let (a, b) = (reach(last), reach(last + step));
+        let overlap = Bounds {
+            min: a.min.max(b.min),
+            max: a.max.min(b.max),
+        };
+        place(rng, overlap, 12);
+        scanners.push(last + step);
+    }
+    for &scanner in &scanners {
+        let count = rng.gen_range(0..=14);
+        place(rng, reach(scanner), count);
+    }
+    (scanners, beacons)
+}
+
+// What each scanner reports of the map, turned some random way round
+fn reports(rng: &mut Random, scanners: &[Vec3], beacons: &[Vec3]) -> String {
+    let mut lines = vec![];
+    for (n, &scanner) in scanners.iter().enumerate() {
+        let rotate = *ROTATIONS.choose(rng).unwrap();
+        let mut seen = beacons
+            .iter()
+            .filter(|&&b| reach(scanner).contains(b))
+            .map(|&b| rotate * (b - scanner))
+            .collect_vec();
+        seen.shuffle(rng);
+        lines.push(format!("--- scanner {} ---", n));
+        lines.extend(seen.iter().map(|p| p.to_string()));
+        lines.push(String::new());
+    }
+    lines.pop();
+    lines.join("\n")
+}
+
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let (scanners, beacons) = random_map(rng, size);
+    reports(rng, &scanners, &beacons)
+}
+
+#[test]
+fn test_random_map() {
+    let mut rng = crate::random::seeded(19);
+    let (scanners, beacons) = random_map(&mut rng, 4);
+    let input = generate(&reports(&mut rng, &scanners, &beacons)).unwrap();
+    assert_eq!(count_beacons(&input), beacons.len());
+    let widest = scanners
+        .iter()
+        .cartesian_product(&scanners)
+        .map(|(a, b)| a.manhattan(*b))
+        .max()
+        .unwrap();
+    assert_eq!(how_wide_was_it(&input), widest);
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Configured<Vec<Scanner>, Day19Params>;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const RANDOM_SIZE: usize = 12;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        assemble_points(&input.input, input.params.overlap)
+            .0
+            .to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        widest(&assemble_points(&input.input, input.params.overlap).1).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+}
@@ -0,0 +1,283 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::{grid, Random};
+use crate::render::{Picture, Rgb};
+use crate::solver::Solver;
+use rand::Rng;
+
+use itertools::Itertools;
+
+// The image is infinite, but beyond what we have seen every pixel is the
+// same, and that is the background of our grid
+#[derive(Debug, PartialEq, Clone)]
+pub struct Image {
+    algorithm: [u8; 512],
+    pixels: Grid<u8>,
+}
+
+impl Image {
+    fn pixel_count(&self) -> usize {
+        self.pixels.iter().filter(|&&p| p == 1).count()
+    }
+}
+
+// Decode a line of # and . pixels into 1s and 0s
+fn to_bits(line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(1),
+            '.' => Ok(0),
+            _ => Err(ParseError::at(20, line, &line[i..], "# or .")),
+        })
+        .collect()
+}
+
+#[aoc_generator(day20)]
+fn generate(input: &str) -> Result<Image, ParseError> {
+    let all = input.lines().collect_vec();
+    let first = all.first().copied().unwrap_or_default();
+    let algorithm = to_bits(first)?
+        .try_into()
+        .map_err(|_| ParseError::at(20, first, "", "512 pixels of algorithm"))?;
+    if !matches!(all.get(1), Some(&"")) {
+        return Err(ParseError::new(20, 2, 1, "a blank line"));
+    }
+    let pixels = Grid::parse(20, &all[2..].join("\n"), "# or .", |c| match c {
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    })
+    .map_err(|e| e.on_line(3))?
+    .with_background(0);
+
+    Ok(Image { algorithm, pixels })
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("..#.#\n\n#..#.").err(),
+        Some(ParseError::new(20, 1, 6, "512 pixels of algorithm"))
+    );
+
+    let algorithm = include_str!("day20_example.txt").lines().next().unwrap();
+    assert_eq!(
+        generate(&format!("{}\n#..#.", algorithm)).err(),
+        Some(ParseError::new(20, 2, 1, "a blank line"))
+    );
+    assert_eq!(
+        generate(&format!("{}\n\n#..#.\n#.O..", algorithm)).err(),
+        Some(ParseError::new(20, 4, 3, "# or ."))
+    );
+}
+
+#[test]
+fn test_generate() {
+    let image = generate(include_str!("day20_example.txt")).unwrap();
+    assert_eq!(image.algorithm[0..4], [0, 0, 1, 0]);
+    assert_eq!((image.pixels.width(), image.pixels.height()), (5, 5));
+}
+
+#[test]
+fn test_image_pixel_count() {
+    let image = generate(include_str!("day20_example.txt")).unwrap();
+    assert_eq!(image.pixel_count(), 10);
+}
+
+use itertools::iproduct;
+
+impl Image {
+    fn neighbour_string(&self, x: isize, y: isize) -> String {
+        iproduct!((y - 1)..=(y + 1), (x - 1)..=(x + 1))
+            .map(|(y, x)| match self.pixels.get(x, y) {
+                Some(1) => '1',
+                _ => '0',
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_neighbour_string() {
+    let mut image = generate(include_str!("day20_example.txt")).unwrap();
+    assert_eq!(image.neighbour_string(0, 0), "000010010");
+    assert_eq!(image.neighbour_string(2, 2), "000100010");
+
+    // When out of bounds, we should use the background char, which defaults to 0
+    assert_eq!(image.neighbour_string(-1000, -1000), "000000000");
+    image.pixels.set_background(1);
+    assert_eq!(image.neighbour_string(-1000, -1000), "111111111");
+}
+
+impl Image {
+    fn step(&mut self) {
+        // Pixels just beyond the edge may light up, but no further than that
+        let mut next = self.pixels.pad(1, 0);
+
+        for (x, y) in next.positions().collect_vec() {
+            let index =
+                usize::from_str_radix(&self.neighbour_string(x as isize - 1, y as isize - 1), 2)
+                    .unwrap();
+            next[(x, y)] = self.algorithm[index];
+        }
+
+        // calculate next background value
+        let index = usize::from_str_radix(&self.neighbour_string(-1000, -1000), 2).unwrap();
+        next.set_background(self.algorithm[index]);
+        self.pixels = next;
+    }
+}
+
+fn enhanced(image: &Image, count: usize) -> Image {
+    let mut image = (*image).clone();
+    for _ in 0..count {
+        image.step();
+    }
+    image
+}
+
+fn apply_steps(image: &Image, count: usize) -> usize {
+    enhanced(image, count).pixel_count()
+}
+
+impl Image {
+    // Lit pixels in white
+    fn picture(&self) -> Picture {
+        self.pixels
+            .map(|&p| if p == 1 { Rgb::WHITE } else { Rgb::BLACK })
+    }
+}
+
+// Every step of the enhancement, each framed as large as the last
+fn growing(image: &Image, steps: usize) -> Vec<Picture> {
+    let mut image = image.clone();
+    let mut frames = vec![];
+    for n in 0..=steps {
+        let background = *image.pixels.background().unwrap();
+        let framed = Image {
+            algorithm: image.algorithm,
+            pixels: image.pixels.pad(steps - n, background),
+        };
+        frames.push(framed.picture());
+        image.step();
+    }
+    frames
+}
+
+#[test]
+fn test_apply_steps() {
+    let image = generate(include_str!("day20_example.txt")).unwrap();
+    assert_eq!(apply_steps(&image, 2), 35);
+    assert_eq!(apply_steps(&image, 50), 3351);
+
+    let picture = enhanced(&image, 2).picture();
+    assert_eq!((picture.width(), picture.height()), (9, 9));
+    assert_eq!(picture.iter().filter(|&&p| p == Rgb::WHITE).count(), 35);
+
+    let frames = growing(&image, 2);
+    assert_eq!(frames.len(), 3);
+    assert!(frames.iter().all(|f| f.width() == 9 && f.height() == 9));
+    assert_eq!(frames[2], picture);
+}
+
+// How many times each part enhances the image, up to 200
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day20Params {
+    pub part1_enhancements: usize,
+    pub part2_enhancements: usize,
+}
+
+impl Default for Day20Params {
+    fn default() -> Self {
+        Day20Params {
+            part1_enhancements: 2,
+            part2_enhancements: 50,
+        }
+    }
+}
+
+impl Params for Day20Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part1_enhancements" => self.part1_enhancements = params::between(name, value, 0, 200)?,
+            "part2_enhancements" => self.part2_enhancements = params::between(name, value, 0, 200)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Day20Params {
+    fn enhancements(&self, part: u32) -> usize {
+        match part {
+            1 => self.part1_enhancements,
+            _ => self.part2_enhancements,
+        }
+    }
+}
+
+#[aoc(day20, part1)]
+fn enhance(image: &Image) -> usize {
+    apply_steps(image, Day20Params::default().part1_enhancements)
+}
+
+#[aoc(day20, part2)]
+fn really_enhance(image: &Image) -> usize {
+    apply_steps(image, Day20Params::default().part2_enhancements)
+}
+
+// When the algorithm lights every dark pixel in the infinite background, it
+// has to turn them off again next time, or the answers would be infinite
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let mut algorithm = (0..512)
+        .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+        .collect_vec();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let image = grid(
+        rng,
+        size,
+        size,
+        |rng| if rng.gen_bool(0.5) { '#' } else { '.' },
+    );
+    format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), image)
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Configured<Image, Day20Params>;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day20_example.txt"));
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        apply_steps(&input.input, input.params.enhancements(1)).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        apply_steps(&input.input, input.params.enhancements(2)).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+
+    fn render(input: &Self::Input, part: u32) -> Option<Picture> {
+        let steps = input.params.enhancements(part);
+        Some(enhanced(&input.input, steps).picture())
+    }
+
+    fn animate(input: &Self::Input, part: u32) -> Option<Vec<Picture>> {
+        Some(growing(&input.input, input.params.enhancements(part)))
+    }
+}
@@ -0,0 +1,296 @@
+use crate::error::{number, ParseError};
+use crate::params::{self, Configured, ParamError, Params};
+use crate::random::Random;
+use crate::solver::Solver;
+use rand::Rng;
+use std::cmp::{max, min};
+
+#[derive(Clone)]
+pub struct Game {
+    positions: [i32; 2],
+    scores: [i32; 2],
+    die: i32,
+    rolls: i32,
+}
+
+impl Game {
+    fn new(player1: i32, player2: i32) -> Self {
+        Self {
+            positions: [player1, player2],
+            scores: [0, 0],
+            die: 0,
+            rolls: 0,
+        }
+    }
+}
+
+impl Game {
+    fn roll(&mut self) -> i32 {
+        self.rolls += 1;
+        self.die += 1;
+        self.die %= 100;
+        if self.die == 0 {
+            100
+        } else {
+            self.die
+        }
+    }
+
+    fn turn(&mut self, player: usize) {
+        let rolled = self.roll() + self.roll() + self.roll();
+        self.positions[player] += rolled;
+        self.positions[player] %= 10;
+        self.scores[player] += self.positions[player] + 1;
+    }
+
+    fn game_won(&self, target: i32) -> bool {
+        self.scores[0] >= target || self.scores[1] >= target
+    }
+}
+
+#[test]
+fn test_die() {
+    let mut game = Game::new(0, 0);
+
+    assert_eq!(game.roll(), 1);
+    assert_eq!(game.roll(), 2);
+    game.die = 99;
+    assert_eq!(game.roll(), 100);
+    assert_eq!(game.roll(), 1);
+    assert_eq!(game.rolls, 4);
+}
+
+#[test]
+fn test_game() {
+    let mut game = Game::new(3, 7);
+    game.turn(0);
+    assert_eq!(game.positions, [9, 7]);
+    assert_eq!(game.scores, [10, 0]);
+    assert_eq!(game.rolls, 3);
+
+    game.turn(1);
+    assert_eq!(game.positions, [9, 2]);
+    assert_eq!(game.scores, [10, 3]);
+    assert_eq!(game.rolls, 6);
+
+    game.play_till_won(1000);
+    assert_eq!(game.positions, [9, 2]);
+    assert_eq!(game.scores, [1000, 745]);
+    assert_eq!(game.rolls, 993);
+}
+
+#[aoc_generator(day21)]
+fn generate(input: &str) -> Result<Game, ParseError> {
+    let positions = input
+        .lines()
+        .enumerate()
+        .map(|(n, l)| {
+            let position = l.split(' ').next_back().unwrap();
+            match number::<i32>(21, l, position) {
+                Ok(p) if (1..=10).contains(&p) => Ok(p),
+                _ => Err(ParseError::at(21, l, position, "a position from 1 to 10")),
+            }
+            .map_err(|e| e.on_line(n + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if positions.len() != 2 {
+        return Err(ParseError::new(
+            21,
+            positions.len().min(2) + 1,
+            1,
+            "two players",
+        ));
+    }
+    Ok(Game::new(positions[0] - 1, positions[1] - 1))
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("Player 1 starting position: 4\nPlayer 2 starting position: 11").err(),
+        Some(ParseError::new(21, 2, 29, "a position from 1 to 10"))
+    );
+    assert_eq!(
+        generate("Player 1 starting position: 4").err(),
+        Some(ParseError::new(21, 2, 1, "two players"))
+    );
+}
+
+#[test]
+fn test_generate() {
+    let game = generate(include_str!("day21_example.txt")).unwrap();
+    assert_eq!(game.positions, [3, 7]);
+}
+
+impl Game {
+    fn play_till_won(&mut self, target: i32) {
+        let mut player = 0;
+        while !self.game_won(target) {
+            self.turn(player);
+            player += 1;
+            player %= 2;
+        }
+    }
+}
+
+// The scores that win each part's game.  Past 10000 part 1's answer
+// overflows, and past 50 part 2 has long since run out of universes to count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day21Params {
+    pub part1_target: i32,
+    pub part2_target: i32,
+}
+
+impl Default for Day21Params {
+    fn default() -> Self {
+        Day21Params {
+            part1_target: 1000,
+            part2_target: 21,
+        }
+    }
+}
+
+impl Params for Day21Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part1_target" => self.part1_target = params::between(name, value, 1, 10000)?,
+            "part2_target" => self.part2_target = params::between(name, value, 1, 50)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
+fn losing_factor_to(game: &Game, target: i32) -> i32 {
+    let mut game = (*game).clone();
+    game.play_till_won(target);
+    min(game.scores[0], game.scores[1]) * game.rolls
+}
+
+#[aoc(day21, part1)]
+fn losing_factor(game: &Game) -> i32 {
+    losing_factor_to(game, Day21Params::default().part1_target)
+}
+
+#[test]
+fn test_losing_factor_to() {
+    // Player 1 reaches 10 on their first turn, 14 on their second
+    assert_eq!(losing_factor_to(&Game::new(3, 7), 10), 0);
+    assert_eq!(losing_factor_to(&Game::new(3, 7), 11), 3 * 9);
+}
+
+fn make_move(
+    positions: [i32; 2],
+    scores: [i32; 2],
+    roll: i32,
+    to_play: usize,
+) -> ([i32; 2], [i32; 2]) {
+    let mut pos = positions;
+    let mut score = scores;
+
+    pos[to_play] += roll;
+    pos[to_play] %= 10;
+
+    score[to_play] += pos[to_play] + 1;
+
+    (pos, score)
+}
+
+use memoize::memoize;
+#[memoize]
+// None once there are too many universes to count in a u64
+fn wins_in_space(
+    positions: [i32; 2],
+    scores: [i32; 2],
+    to_play: usize,
+    target: i32,
+) -> Option<(u64, u64)> {
+    // Base case, this is a won game for player 1
+    if scores[0] >= target {
+        return Some((1, 0));
+    }
+
+    // player 2 win
+    if scores[1] >= target {
+        return Some((0, 1));
+    }
+
+    let mut winners = (0, 0);
+    // When we roll 3 Dirac dice, we get one of the following total rolls at each
+    // given frequency, so we recurse just on the roll totals and current game state.
+    for [roll, frequency] in [[3, 1], [4, 3], [5, 6], [6, 7], [7, 6], [8, 3], [9, 1]] {
+        let (positions, scores) = make_move(positions, scores, roll, to_play);
+        let wins = wins_in_space(positions, scores, 1 - to_play, target)?;
+
+        // Now we know who won in that branch of the game, multiply it back up
+        // by frequency to count the number of universes that happened in.
+        winners.0 = (wins.0.checked_mul(frequency as u64)?).checked_add(winners.0)?;
+        winners.1 = (wins.1.checked_mul(frequency as u64)?).checked_add(winners.1)?;
+    }
+    Some(winners)
+}
+
+fn winning_universes_to(game: &Game, target: i32) -> String {
+    wins_in_space(game.positions, game.scores, 0, target).map_or_else(
+        || String::from("too many universes for a u64"),
+        |(p1, p2)| max(p1, p2).to_string(),
+    )
+}
+
+#[aoc(day21, part2)]
+fn winning_universes(game: &Game) -> String {
+    winning_universes_to(game, Day21Params::default().part2_target)
+}
+
+#[test]
+fn test_winning_universes() {
+    assert_eq!(winning_universes(&Game::new(3, 7)), "444356092776315");
+    assert_eq!(
+        winning_universes_to(&Game::new(3, 7), 30),
+        "too many universes for a u64"
+    );
+}
+
+#[test]
+fn test_configure() {
+    let mut input = Day21::parse(include_str!("day21_example.txt")).unwrap();
+    Day21::configure(&mut input, "part2_target", "10").unwrap();
+    assert_eq!(input.params.part2_target, 10);
+    assert!(Day21::configure(&mut input, "part2_target", "51").is_err());
+    assert!(Day21::configure(&mut input, "part1_target", "0").is_err());
+    assert!(Day21::configure(&mut input, "part1_target", "10001").is_err());
+}
+
+fn random_input(rng: &mut Random, _size: usize) -> String {
+    (1..=2)
+        .map(|n| format!("Player {} starting position: {}", n, rng.gen_range(1..=10)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = Configured<Game, Day21Params>;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day21_example.txt"));
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Configured::new(generate(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        losing_factor_to(&input.input, input.params.part1_target).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        winning_universes_to(&input.input, input.params.part2_target)
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn configure(input: &mut Self::Input, name: &str, value: &str) -> Result<(), ParamError> {
+        input.params.set(name, value)
+    }
+}
@@ -0,0 +1,350 @@
+use crate::error::{number, parse_lines, ParseError};
+use crate::random::{lines, Random};
+use crate::solver::{Solver, Variant};
+use crate::trace::trace;
+use rand::Rng;
+use std::collections::HashSet;
+
+use euclid::{box3d, default::Box3D};
+use itertools::{iproduct, Itertools};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+trait Reactor {
+    fn apply(&mut self, instuction: &Instruction);
+    fn lit_cubes(&self) -> usize;
+}
+
+// Parse the region `input`, reporting errors at their position on `line`
+fn parse_box(line: &str, input: &str) -> Result<Box3D<i64>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^x=(?P<x1>-?\d+)\.\.(?P<x2>-?\d+),y=(?P<y1>-?\d+)\.\.(?P<y2>-?\d+),z=(?P<z1>-?\d+)\.\.(?P<z2>-?\d+)$")
+        .unwrap();
+    }
+    let captures = RE
+        .captures(input)
+        .ok_or_else(|| ParseError::at(22, line, input, "a region x=X1..X2,y=Y1..Y2,z=Z1..Z2"))?;
+    let coord = |name| number::<i64>(22, line, captures.name(name).unwrap().as_str());
+    // The box ends just past the last cube in the region
+    let end = |name| {
+        let token = captures.name(name).unwrap().as_str();
+        number::<i64>(22, line, token)?
+            .checked_add(1)
+            .ok_or_else(|| ParseError::at(22, line, token, "a number below 2^63 - 1"))
+    };
+
+    Ok(box3d(
+        coord("x1")?,
+        coord("y1")?,
+        coord("z1")?,
+        end("x2")?,
+        end("y2")?,
+        end("z2")?,
+    ))
+}
+
+#[derive(PartialEq)]
+enum Operation {
+    On,
+    Off,
+}
+pub struct Instruction {
+    operation: Operation,
+    region: Box3D<i64>,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (op, region) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(22, input, "", "on or off and a region"))?;
+        Ok(Instruction {
+            region: parse_box(input, region)?,
+            operation: match op {
+                "on" => Operation::On,
+                "off" => Operation::Off,
+                _ => return Err(ParseError::at(22, input, op, "on or off")),
+            },
+        })
+    }
+}
+
+#[derive(Clone)]
+struct CountingReactor {
+    cells: HashSet<[i64; 3]>,
+}
+
+impl CountingReactor {
+    fn new() -> Self {
+        Self {
+            cells: HashSet::new(),
+        }
+    }
+}
+
+impl Reactor for CountingReactor {
+    fn lit_cubes(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        for (x, y, z) in iproduct!(
+            instruction.region.x_range(),
+            instruction.region.y_range(),
+            instruction.region.z_range()
+        ) {
+            match instruction.operation {
+                Operation::On => self.cells.insert([x, y, z]),
+                Operation::Off => self.cells.remove(&[x, y, z]),
+            };
+        }
+    }
+}
+
+#[test]
+fn test_instruction_apply() {
+    let mut reactor = CountingReactor::new();
+    reactor.apply(&Instruction::try_from("on x=10..12,y=10..12,z=10..12").unwrap());
+    assert_eq!(reactor.lit_cubes(), 27);
+
+    reactor.apply(&Instruction::try_from("on x=11..13,y=11..13,z=11..13").unwrap());
+    assert_eq!(reactor.lit_cubes(), 46);
+
+    reactor.apply(&Instruction::try_from("off x=9..11,y=9..11,z=9..11").unwrap());
+    assert_eq!(reactor.lit_cubes(), 38);
+
+    reactor.apply(&Instruction::try_from("on x=10..10,y=10..10,z=10..10").unwrap());
+    assert_eq!(reactor.lit_cubes(), 39);
+}
+
+#[aoc_generator(day22)]
+fn generate(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |l| Instruction::try_from(l))
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("on x=10..12,y=10..12,z=10..12\nflip x=1..2,y=1..2,z=1..2").err(),
+        Some(ParseError::new(22, 2, 1, "on or off"))
+    );
+    assert_eq!(
+        generate("on x=10..12,y=10..12,z=10..12\noff x=1..2,y=1..2").err(),
+        Some(ParseError::new(
+            22,
+            2,
+            5,
+            "a region x=X1..X2,y=Y1..Y2,z=Z1..Z2"
+        ))
+    );
+    assert_eq!(
+        generate("on x=10..12,y=10..12,z=10..99999999999999999999").err(),
+        Some(ParseError::new(22, 1, 28, "a number"))
+    );
+    assert_eq!(
+        generate("on x=10..9223372036854775807,y=10..12,z=10..12").err(),
+        Some(ParseError::new(22, 1, 10, "a number below 2^63 - 1"))
+    );
+}
+
+fn boot_reactor(reactor: &mut dyn Reactor, input: &[Instruction]) -> usize {
+    // Only consider operations on our core
+    let bounds = box3d(-50, -50, -50, 51, 51, 51);
+
+    input
+        .iter()
+        .filter(|i| bounds.contains_box(&i.region))
+        .for_each(|i| reactor.apply(i));
+
+    reactor.lit_cubes()
+}
+
+#[aoc(day22, part1)]
+fn lit_initialized_cubes(input: &[Instruction]) -> usize {
+    boot_reactor(&mut CountingReactor::new(), input)
+}
+
+#[test]
+fn test_lit_initialized_cubes() {
+    assert_eq!(
+        lit_initialized_cubes(&generate(include_str!("day22_example.txt")).unwrap()),
+        590784
+    );
+}
+
+#[aoc(day22, part1, slicing)]
+fn lit_initialized_cubes_slicing(input: &[Instruction]) -> usize {
+    boot_reactor(&mut SlicingReactor::new(), input)
+}
+
+#[test]
+fn test_lit_initialized_cubes_slicing() {
+    assert_eq!(
+        lit_initialized_cubes_slicing(&generate(include_str!("day22_example.txt")).unwrap()),
+        590784
+    );
+}
+
+#[test]
+fn test_slicing_matches_counting() {
+    use crate::differential::{assert_agree, shrink_lines};
+    use crate::random::seeded;
+
+    assert_agree(
+        0..20,
+        |seed| random_input(&mut seeded(seed), 40),
+        |input| shrink_lines(input),
+        |input| lit_initialized_cubes(&generate(input).unwrap()),
+        |input| lit_initialized_cubes_slicing(&generate(input).unwrap()),
+    );
+}
+
+#[derive(Default)]
+struct SlicingReactor {
+    lit_regions: Vec<Box3D<i64>>,
+}
+
+fn slice_cube(cube: &Box3D<i64>, cut: &Box3D<i64>) -> Vec<Box3D<i64>> {
+    let mut result = vec![];
+    // Generate cubelets by slicing the cube by its intersecting cut.
+    let xs = [cube.max.x, cube.min.x, cut.max.x, cut.min.x]
+        .into_iter()
+        .sorted()
+        .tuple_windows::<(_, _)>();
+
+    let ys = [cube.max.y, cube.min.y, cut.max.y, cut.min.y]
+        .into_iter()
+        .sorted()
+        .tuple_windows::<(_, _)>();
+
+    let zs = [cube.max.z, cube.min.z, cut.max.z, cut.min.z]
+        .into_iter()
+        .sorted()
+        .tuple_windows::<(_, _)>();
+
+    for ((x1, x2), (y1, y2), (z1, z2)) in iproduct!(xs, ys, zs) {
+        let cubelet = box3d(x1, y1, z1, x2, y2, z2);
+        // Lose the cutting tool
+        if *cut != cubelet {
+            result.push(cubelet);
+        }
+    }
+
+    result
+}
+
+#[test]
+fn test_slice_cube() {
+    assert_eq!(
+        slice_cube(&box3d(0, 0, 0, 10, 10, 10), &box3d(0, 0, 0, 1, 1, 1)).len(),
+        26,
+    );
+}
+
+impl SlicingReactor {
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Reactor for SlicingReactor {
+    fn apply(&mut self, instruction: &Instruction) {
+        let mut regions = vec![];
+
+        for lit in &self.lit_regions {
+            if let Some(intersection) = lit.intersection(&instruction.region) {
+                let pieces = slice_cube(lit, &intersection);
+                trace!("split", region = ?lit, cut = ?intersection, pieces = pieces.len());
+                regions.extend(pieces);
+            } else {
+                regions.push(*lit);
+            }
+        }
+
+        if instruction.operation == Operation::On {
+            regions.push(instruction.region)
+        }
+        self.lit_regions = regions;
+    }
+
+    fn lit_cubes(&self) -> usize {
+        self.lit_regions.iter().map(|b| b.volume() as usize).sum()
+    }
+}
+
+#[aoc(day22, part2)]
+fn lit_all_cubes(input: &[Instruction]) -> usize {
+    let mut reactor = SlicingReactor::new();
+
+    input.iter().for_each(|i| reactor.apply(i));
+
+    reactor.lit_cubes()
+}
+
+#[test]
+fn test_lit_all_cubes() {
+    assert_eq!(
+        lit_all_cubes(&generate(include_str!("day22_example_2.txt")).unwrap()),
+        2758514936282235
+    );
+}
+
+// Up to 20 steps inside the initialization region, then the rest far bigger
+fn random_input(rng: &mut Random, size: usize) -> String {
+    let initialization = (size / 2).clamp(1, 20);
+    let mut step = 0;
+    lines(rng, size.max(1), |rng| {
+        let range = |rng: &mut Random| {
+            let (lo, hi) = if step < initialization {
+                let lo = rng.gen_range(-50..=50);
+                (lo, rng.gen_range(lo..=50.min(lo + 40)))
+            } else {
+                let lo = rng.gen_range(-100000..=90000);
+                (lo, lo + rng.gen_range(1000..=30000))
+            };
+            format!("{}..{}", lo, hi)
+        };
+        let operation = if step == 0 || rng.gen_bool(0.5) {
+            "on"
+        } else {
+            "off"
+        };
+        let (x, y, z) = (range(rng), range(rng), range(rng));
+        step += 1;
+        format!("{} x={},y={},z={}", operation, x, y, z)
+    })
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Vec<Instruction>;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day22_example.txt"));
+    const RANDOM_SIZE: usize = 100;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        lit_initialized_cubes(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        lit_all_cubes(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            part: 1,
+            name: "slicing",
+            run: |input| lit_initialized_cubes_slicing(input).to_string(),
+        }]
+    }
+}
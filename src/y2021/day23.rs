@@ -1,5 +1,10 @@
+use crate::error::ParseError;
+use crate::random::Random;
+use crate::solver::Solver;
+use crate::trace::trace;
 use itertools::Itertools;
 use pathfinding::prelude::dijkstra;
+use rand::seq::SliceRandom;
 
 type Cost = u32;
 
@@ -31,7 +36,6 @@ impl Game {
 fn pod_chars(input: &str) -> Vec<char> {
     input
         .chars()
-        .into_iter()
         .filter(|c| matches!(c, 'A' | 'B' | 'C' | 'D'))
         .collect()
 }
@@ -96,13 +100,12 @@ impl Game {
             }
 
             // We need to try moving into the hallway
-            return self
-                .moves
+            self.moves
                 .iter()
                 .filter(|m| m.start == *cell)
                 .filter(|m| !m.blocked.iter().any(|cell| state.occupied(cell)))
                 .map(|m| (state.make_move(&m.start, &m.end), m.moves * pod_cost(pod)))
-                .collect();
+                .collect()
         } else {
             // We're in the hallway.  We can go home if our homeroom is empty or has no strangers
             let column = pod.to_ascii_lowercase();
@@ -116,7 +119,7 @@ impl Game {
                 return vec![];
             }
 
-            let lowest_spot = (0..self.rows as u8)
+            let lowest_spot = (0..self.rows)
                 .rev()
                 .find(|&index| !state.occupied(&Cell { column, index }))
                 .unwrap();
@@ -126,15 +129,14 @@ impl Game {
                 index: lowest_spot,
             };
 
-            return self
-                .moves
+            self.moves
                 .iter()
                 // inverse path
                 .filter(|m| m.start == home_cell && m.end == *cell)
                 // not blocked
                 .filter(|m| !m.blocked.iter().any(|cell| state.occupied(cell)))
                 .map(|m| (state.make_move(&m.end, &m.start), m.moves * pod_cost(pod)))
-                .collect();
+                .collect()
         }
     }
 }
@@ -429,13 +431,18 @@ impl From<&str> for State {
 #[test]
 fn test_game_state() {
     assert_eq!(
-        generate(include_str!("day23_example.txt")).state,
+        generate(include_str!("day23_example.txt")).unwrap().state,
         "a0=B,a1=A,b0=C,b1=D,c0=B,c1=C,d0=D,d1=A"
     );
 }
 
 fn cheapest_path(game: &Game) -> Cost {
-    if let Some((_, c)) = dijkstra(&game.state, |p| game.legal_moves(p), |p| game.solved(p)) {
+    let expand = |state: &State| {
+        let moves = game.legal_moves(state);
+        trace!("expand", state = state, moves = moves.len());
+        moves
+    };
+    if let Some((_, c)) = dijkstra(&game.state, expand, |p| game.solved(p)) {
         return c;
     }
 
@@ -446,28 +453,59 @@ fn cheapest_path(game: &Game) -> Cost {
 #[test]
 fn test_cheapest_path() {
     assert_eq!(
-        cheapest_path(&generate(include_str!("day23_example.txt"))),
+        cheapest_path(&generate(include_str!("day23_example.txt")).unwrap()),
         12521,
     );
 }
 
+// Like pod_chars, but checking we were given a burrow with two of each amphipod
+fn checked_pods(input: &str) -> Result<Vec<char>, ParseError> {
+    for (n, line) in input.lines().enumerate() {
+        if let Some((i, _)) = line
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '#' | '.' | ' ' | 'A' | 'B' | 'C' | 'D'))
+        {
+            return Err(
+                ParseError::at(23, line, &line[i..], "a wall or an amphipod").on_line(n + 1),
+            );
+        }
+    }
+
+    let pods = pod_chars(input);
+    let counts = pods.iter().counts();
+    if pods.len() != 8 || counts.values().any(|&c| c != 2) {
+        let line = input.lines().count().max(1);
+        return Err(ParseError::new(23, line, 1, "two each of A, B, C and D"));
+    }
+    Ok(pods)
+}
+
+// Unfold the diagram, adding the two hidden rows
+fn splice(pods: &[char]) -> Vec<char> {
+    let (left, right) = pods.split_at(4);
+    [left, &['D', 'C', 'B', 'A', 'D', 'B', 'A', 'C'], right].concat()
+}
+
 #[aoc_generator(day23, part1)]
-fn generate(input: &str) -> Game {
-    Game::from(input)
+fn generate(input: &str) -> Result<Game, ParseError> {
+    checked_pods(input).map(Game::new)
 }
 
 #[aoc_generator(day23, part2)]
-fn generate_spliced(input: &str) -> Game {
-    let pods = pod_chars(input);
-    let (left, right) = pods.split_at(4);
-    Game::from(
-        format!(
-            "{} DCBA DBAC {}",
-            String::from_iter(left),
-            String::from_iter(right)
-        )
-        .as_str(),
-    )
+fn generate_spliced(input: &str) -> Result<Game, ParseError> {
+    checked_pods(input).map(|pods| Game::new(splice(&pods)))
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#E#\n  #########").err(),
+        Some(ParseError::new(23, 4, 10, "a wall or an amphipod"))
+    );
+    assert_eq!(
+        generate("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#C#\n  #########").err(),
+        Some(ParseError::new(23, 5, 1, "two each of A, B, C and D"))
+    );
 }
 
 #[aoc(day23, part1)]
@@ -478,7 +516,7 @@ fn solve(game: &Game) -> Cost {
 
 #[test]
 fn test_spliced() {
-    let game = generate_spliced(include_str!("day23_example.txt"));
+    let game = generate_spliced(include_str!("day23_example.txt")).unwrap();
     assert_eq!(game.rows, 4);
     assert_eq!(
         game.state,
@@ -487,3 +525,37 @@ fn test_spliced() {
         )
     );
 }
+
+// Two of each amphipod, in any order
+fn random_input(rng: &mut Random, _size: usize) -> String {
+    let mut pods = "AABBCCDD".chars().collect_vec();
+    pods.shuffle(rng);
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########",
+        pods[..4].iter().join("#"),
+        pods[4..].iter().join("#")
+    )
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Vec<char>;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day23_example.txt"));
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        checked_pods(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        solve(&Game::new(input.clone())).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        solve(&Game::new(splice(input))).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
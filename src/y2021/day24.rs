@@ -1,26 +1,43 @@
+use crate::error::{number, parse_lines, ParseError};
+use crate::random::Random;
+use crate::solver::Solver;
+use crate::trace::trace;
+use rand::Rng;
 use std::collections::HashMap;
 
 type Register = char;
 type Value = i64;
 
 #[derive(Debug, Clone, Copy)]
-enum Argument {
+pub enum Argument {
     Register(char),
     Value(Value),
 }
 
-impl From<&str> for Argument {
-    fn from(input: &str) -> Self {
-        if "wxyz".contains(input) {
-            Argument::Register(input.chars().next().unwrap())
-        } else {
-            Argument::Value(input.parse().unwrap())
+fn register(line: &str, input: &str) -> Result<Register, ParseError> {
+    match input {
+        "w" | "x" | "y" | "z" => Ok(input.chars().next().unwrap()),
+        _ => Err(ParseError::at(24, line, input, "a register w, x, y or z")),
+    }
+}
+
+impl Argument {
+    // Parse the argument `input`, reporting errors at their position on `line`
+    fn parse(line: &str, input: &str) -> Result<Self, ParseError> {
+        match register(line, input) {
+            Ok(r) => Ok(Argument::Register(r)),
+            Err(_) => number(24, line, input)
+                .map(Argument::Value)
+                .map_err(|e| ParseError {
+                    expected: String::from("a register or a number"),
+                    ..e
+                }),
         }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Instruction {
+pub enum Instruction {
     Inp(Register),
     Add(Register, Argument),
     Mul(Register, Argument),
@@ -29,24 +46,36 @@ enum Instruction {
     Eql(Register, Argument),
 }
 
-impl From<&str> for Instruction {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let mut parts = input.split_ascii_whitespace();
-        let keyword = parts.next().unwrap();
-        let dest = parts.next().unwrap().chars().next().unwrap();
+        let mut next = |expected| {
+            parts
+                .next()
+                .ok_or_else(|| ParseError::at(24, input, "", expected))
+        };
+        let keyword = next("an instruction")?;
+        let dest = register(input, next("a register w, x, y or z")?)?;
         match keyword {
-            "inp" => Instruction::Inp(dest),
-            _ => {
-                let src = Argument::from(parts.next().unwrap());
-                match keyword {
+            "inp" => Ok(Instruction::Inp(dest)),
+            "add" | "mul" | "div" | "mod" | "eql" => {
+                let src = Argument::parse(input, next("a register or a number")?)?;
+                Ok(match keyword {
                     "add" => Instruction::Add(dest, src),
                     "mul" => Instruction::Mul(dest, src),
                     "div" => Instruction::Div(dest, src),
                     "mod" => Instruction::Mod(dest, src),
-                    "eql" => Instruction::Eql(dest, src),
-                    _ => unreachable!(),
-                }
+                    _ => Instruction::Eql(dest, src),
+                })
             }
+            _ => Err(ParseError::at(
+                24,
+                input,
+                keyword,
+                "inp, add, mul, div, mod or eql",
+            )),
         }
     }
 }
@@ -145,14 +174,14 @@ impl Alu {
 
 #[test]
 fn test_alu_example1() {
-    let mut alu = Alu::new(&generate("inp x\nmul x -1"));
+    let mut alu = Alu::new(&generate("inp x\nmul x -1").unwrap());
     alu.run(&[42]);
     assert_eq!(alu.x, -42);
 }
 
 #[test]
 fn test_alu_example2() {
-    let mut alu = Alu::new(&generate("inp z\ninp x\nmul z 3\neql z x"));
+    let mut alu = Alu::new(&generate("inp z\ninp x\nmul z 3\neql z x").unwrap());
     alu.run(&[1, 3]);
     assert_eq!(alu.x, 3);
     assert_eq!(alu.z, 1);
@@ -164,7 +193,7 @@ fn test_alu_example2() {
 
 #[test]
 fn test_alu_example3() {
-    let mut alu = Alu::new(&generate(include_str!("day24_example3.txt")));
+    let mut alu = Alu::new(&generate(include_str!("day24_example3.txt")).unwrap());
     assert!(alu.run(&[0b0000]));
     assert_eq!([alu.w, alu.x, alu.y, alu.z], [0, 0, 0, 0]);
 
@@ -183,7 +212,7 @@ fn find_model_number(program: &[Instruction], keep: fn(Value, Value) -> bool) ->
     let mut digits: HashMap<Value, Value> = HashMap::new();
     digits.insert(0, 0);
 
-    for (_p, program) in programs.enumerate() {
+    for (digit, program) in programs.enumerate() {
         let mut new_digits: HashMap<Value, Value> = HashMap::new();
         let mut alu = Alu::new(program);
         for candidate in 1..=9 {
@@ -201,14 +230,34 @@ fn find_model_number(program: &[Instruction], keep: fn(Value, Value) -> bool) ->
             }
         }
         digits = new_digits;
-        // println!("{} digits checked, {} entries", _p, digits.len());
+        trace!("digit", checked = digit + 1, states = digits.len());
     }
 
     digits[&0]
 }
 #[aoc_generator(day24)]
-fn generate(input: &str) -> Vec<Instruction> {
-    input.lines().map(Instruction::from).collect()
+fn generate(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |l| Instruction::try_from(l))
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("inp w\nsub x 1").err(),
+        Some(ParseError::new(24, 2, 1, "inp, add, mul, div, mod or eql"))
+    );
+    assert_eq!(
+        generate("inp w\nadd a 1").err(),
+        Some(ParseError::new(24, 2, 5, "a register w, x, y or z"))
+    );
+    assert_eq!(
+        generate("inp w\nadd x one").err(),
+        Some(ParseError::new(24, 2, 7, "a register or a number"))
+    );
+    assert_eq!(
+        generate("inp w\nadd x").err(),
+        Some(ParseError::new(24, 2, 6, "a register or a number"))
+    );
 }
 
 #[aoc(day24, part1)]
@@ -220,3 +269,62 @@ fn largest_model_number(program: &[Instruction]) -> Value {
 fn smallest_model_number(program: &[Instruction]) -> Value {
     find_model_number(program, |old, new| old > new || old == 0)
 }
+
+// A MONAD like the real ones: fourteen blocks reading a digit each, seven of
+// them pushing the digit plus some offset onto z as a stack in base 26, and
+// seven popping one back off to check it against their own digit.  The checks
+// pair the digits up, no more than 8 apart, so some model number passes.
+fn random_input(rng: &mut Random, _size: usize) -> String {
+    let mut blocks = vec![];
+    let mut stack = vec![];
+    let mut pushes = 7;
+    for n in 0..14 {
+        let pops = 14 - n - pushes;
+        let push = pushes > 0 && (stack.is_empty() || pops == 0 || rng.gen_bool(0.5));
+        let offset: Value = rng.gen_range(1..=16);
+        if push {
+            pushes -= 1;
+            stack.push(offset);
+            blocks.push((1, rng.gen_range(10..=16), offset));
+        } else {
+            let pushed = stack.pop().unwrap();
+            let apart: Value = rng.gen_range(-8..=8);
+            blocks.push((26, apart - pushed, offset));
+        }
+    }
+
+    blocks
+        .iter()
+        .map(|(div, check, offset)| {
+            format!(
+                "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                 mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                 mul y x\nadd z y",
+                div, check, offset
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        largest_model_number(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        smallest_model_number(input).to_string()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+}
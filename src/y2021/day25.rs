@@ -0,0 +1,175 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::random::{grid, Random};
+use crate::render::{Picture, Rgb};
+use crate::solver::Solver;
+use rand::Rng;
+
+pub type Cucumbers = Grid<char>;
+
+#[test]
+fn test_generate() {
+    let state = generate("...>>>>>...").unwrap();
+    assert_eq!((state.width(), state.height()), (11, 1));
+    assert_eq!(state[(1, 0)], '.');
+}
+
+fn step(state: &Cucumbers) -> Cucumbers {
+    let state = step_right(state);
+    step_down(&state)
+}
+
+fn step_down(state: &Cucumbers) -> Cucumbers {
+    step_herd(state, 'v', (0, 1))
+}
+
+fn step_right(state: &Cucumbers) -> Cucumbers {
+    step_herd(state, '>', (1, 0))
+}
+
+// Every cucumber of a herd that can move does so at once, off one edge of
+// the map and back onto the other
+fn step_herd(state: &Cucumbers, herd: char, (dx, dy): (isize, isize)) -> Cucumbers {
+    let mut moves = vec![];
+    for ((x, y), &c) in state.indexed_iter() {
+        let to = state.wrap(x as isize + dx, y as isize + dy);
+        if c == herd && state[to] == '.' {
+            moves.push(((x, y), to));
+        }
+    }
+
+    let mut next = state.clone();
+    for (from, to) in moves {
+        next[to] = next[from];
+        next[from] = '.';
+    }
+
+    next
+}
+
+#[test]
+fn test_step_east_moves() {
+    let state = generate("...>>>>>...").unwrap();
+
+    let state = step_right(&state);
+    assert_eq!(state, generate("...>>>>.>..").unwrap(), "1 step");
+
+    let state = step_right(&state);
+    assert_eq!(state, generate("...>>>.>.>.").unwrap());
+}
+
+#[aoc_generator(day25)]
+fn generate(input: &str) -> Result<Cucumbers, ParseError> {
+    Grid::parse(25, input, ">, v or .", |c| {
+        matches!(c, '.' | '>' | 'v').then_some(c)
+    })
+}
+
+#[test]
+fn test_generate_errors() {
+    assert_eq!(
+        generate("...>>>>>...\n..v.").err(),
+        Some(ParseError::new(25, 2, 5, "11 columns"))
+    );
+    assert_eq!(
+        generate("...>>>>>...\n..v..<.....").err(),
+        Some(ParseError::new(25, 2, 6, ">, v or ."))
+    );
+}
+
+// Steps until the herds stop moving, and where they stopped
+fn settle(start: &Cucumbers) -> (usize, Cucumbers) {
+    let mut iteration = 0;
+    let mut prev = (*start).clone();
+    loop {
+        let next = step(&prev);
+        iteration += 1;
+
+        if next == prev {
+            return (iteration, next);
+        }
+
+        prev = next;
+    }
+}
+
+#[aoc(day25, part1)]
+fn safe_iteration(start: &Cucumbers) -> usize {
+    settle(start).0
+}
+
+// East-facing herd in red, south-facing in blue, on the dark sea floor
+fn picture(state: &Cucumbers) -> Picture {
+    state.map(|c| match c {
+        '>' => Rgb::RED,
+        'v' => Rgb(40, 90, 220),
+        _ => Rgb::BLACK,
+    })
+}
+
+#[test]
+fn test_safe_iteration() {
+    assert_eq!(
+        safe_iteration(&generate(include_str!("day25_example.txt")).unwrap()),
+        58
+    );
+}
+
+#[test]
+fn test_picture() {
+    let picture = picture(&generate("...>>v").unwrap());
+    assert_eq!(picture[(0, 0)], Rgb::BLACK);
+    assert_eq!(picture[(3, 0)], Rgb::RED);
+}
+
+// About as crowded as the real sea floor
+fn random_input(rng: &mut Random, size: usize) -> String {
+    grid(rng, size, size, |rng| match rng.gen_range(0..100) {
+        0..=44 => '.',
+        45..=72 => '>',
+        _ => 'v',
+    })
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Cucumbers;
+    const EXAMPLE: Option<&'static str> = Some(include_str!("day25_example.txt"));
+    const RANDOM_SIZE: usize = 100;
+    const PARTS: u32 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        safe_iteration(input).to_string()
+    }
+
+    fn part2(_: &Self::Input) -> String {
+        // There's no second puzzle on Christmas day
+        unreachable!()
+    }
+
+    fn random(rng: &mut Random, size: usize) -> String {
+        random_input(rng, size)
+    }
+
+    fn render(input: &Self::Input, _part: u32) -> Option<Picture> {
+        Some(picture(&settle(input).1))
+    }
+
+    fn animate(input: &Self::Input, _part: u32) -> Option<Vec<Picture>> {
+        let mut state = input.clone();
+        let mut frames = vec![picture(&state)];
+        loop {
+            let next = step(&state);
+            if next == state {
+                return Some(frames);
+            }
+            frames.push(picture(&next));
+            state = next;
+        }
+    }
+}